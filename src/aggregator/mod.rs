@@ -0,0 +1,181 @@
+//! # Example
+//! ```
+//! let mut minute = Aggregator::new(Duration::from_secs(60));
+//! let mut quarter = Aggregator::new(Duration::from_secs(900));
+//! // converted variable from converter output, Time is set by parser
+//! if let Some(stats) = minute.push(&converted) {
+//!     println!("{:?}", stats.numeric.get("V"));
+//! }
+//! quarter.push(&converted);
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Labels, VEDirectData};
+use crate::metadata::label_metadata;
+use crate::metadata::models::DataType;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+use strum::IntoEnumIterator;
+
+#[derive(PartialEq, Debug, Clone, Default)]
+struct NumericAccumulator {
+    min: Option<f64>,
+    max: Option<f64>,
+    sum: f64,
+    last: Option<f64>,
+    count: u64,
+    non_finite: u64,
+}
+
+impl NumericAccumulator {
+    fn push(&mut self, val: f64) {
+        // infinite time-to-go would turn max and mean infinite, which JSON cannot represent
+        if !val.is_finite() {
+            self.non_finite += 1;
+            return;
+        }
+        self.min = Some(self.min.map_or(val, |min| min.min(val)));
+        self.max = Some(self.max.map_or(val, |max| max.max(val)));
+        self.sum += val;
+        self.last = Some(val);
+        self.count += 1;
+    }
+
+    fn stats(&self) -> NumericStats {
+        NumericStats {
+            min: self.min,
+            max: self.max,
+            mean: self.last.map(|_| self.sum / self.count as f64),
+            last: self.last,
+            count: self.count,
+            non_finite: self.non_finite,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+struct EnumAccumulator {
+    counts: Vec<(Value, u64)>, // in order of first appearance
+    last: Value,
+    count: u64,
+}
+
+impl EnumAccumulator {
+    fn push(&mut self, val: Value) {
+        match self.counts.iter_mut().find(|(value, _)| *value == val) {
+            Some((_, count)) => *count += 1,
+            None => self.counts.push((val.clone(), 1)),
+        }
+        self.last = val;
+        self.count += 1;
+    }
+
+    fn stats(&self) -> EnumStats {
+        let mut mode = &Value::Null;
+        let mut mode_count = 0;
+        for (value, count) in self.counts.iter() {
+            if *count > mode_count {
+                mode = value;
+                mode_count = *count;
+            }
+        }
+        EnumStats {
+            mode: mode.clone(),
+            last: self.last.clone(),
+            count: self.count,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Window {
+    start: i64,
+    frames: u64,
+    numeric: HashMap<Labels, NumericAccumulator>,
+    enums: HashMap<Labels, EnumAccumulator>,
+}
+
+/// Aggregates frames into windows aligned to multiples of window length since unix epoch
+#[derive(PartialEq, Debug, Clone)]
+pub struct Aggregator {
+    length: i64,
+    numeric_labels: Vec<Labels>,
+    enum_labels: Vec<Labels>,
+    window: Option<Window>,
+}
+
+impl Aggregator {
+    pub fn new(length: Duration) -> Self {
+        let labels_of = |data_types: &[DataType]| -> Vec<Labels> {
+            Labels::iter()
+                .filter(|label| data_types.contains(&label_metadata(*label).data_type))
+                .collect()
+        };
+        Self {
+            length: (length.as_secs() as i64).max(1),
+            numeric_labels: labels_of(&[DataType::Number, DataType::Duration]),
+            enum_labels: labels_of(&[DataType::Enum, DataType::Flags]),
+            window: None,
+        }
+    }
+
+    /// adds frame to its window, returns statistics of previous window when frame starts a new one,
+    /// frames without Time or older than current window are skipped
+    pub fn push(&mut self, data: &VEDirectData) -> Option<WindowStats> {
+        let time = data.Time?;
+        let start = time - time.rem_euclid(self.length);
+
+        let finished = match self.window.as_ref() {
+            Some(window) if start < window.start => return None,
+            Some(window) if start > window.start => self.flush(),
+            _ => None,
+        };
+
+        let window = self.window.get_or_insert_with(|| Window {
+            start,
+            frames: 0,
+            numeric: HashMap::new(),
+            enums: HashMap::new(),
+        });
+        window.frames += 1;
+        for label in self.numeric_labels.iter() {
+            if let Some(val) = data.numeric(*label) {
+                window.numeric.entry(*label).or_default().push(val);
+            }
+        }
+        for label in self.enum_labels.iter().filter(|label| data.has_value(**label)) {
+            // serialising unit enums and lists of flags cannot fail
+            if let Ok(val) = data.json_value(*label) {
+                window.enums.entry(*label).or_default().push(val);
+            }
+        }
+
+        finished
+    }
+
+    /// statistics of current window, aggregation starts anew with next frame
+    pub fn flush(&mut self) -> Option<WindowStats> {
+        let window = self.window.take()?;
+        Some(WindowStats {
+            start: window.start,
+            end: window.start + self.length,
+            frames: window.frames,
+            numeric: window
+                .numeric
+                .iter()
+                .map(|(label, acc)| (label.to_string(), acc.stats()))
+                .collect(),
+            enums: window
+                .enums
+                .iter()
+                .map(|(label, acc)| (label.to_string(), acc.stats()))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Statistics of numeric field within window, values in normalised unit of the field.
+/// Non-finite values (infinite time-to-go) are counted apart, statistics are `None` when there were only such values.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct NumericStats {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    pub last: Option<f64>,
+    pub count: u64,      // finite values
+    pub non_finite: u64, // values left out of statistics
+}
+
+/// Statistics of enum or flags field within window, values in JSON representation of the field
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct EnumStats {
+    pub mode: serde_json::Value, // most frequent value, earliest one on tie
+    pub last: serde_json::Value,
+    pub count: u64,
+}
+
+/// Aggregated window, fields are keyed by label as sent by device
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WindowStats {
+    pub start: i64, // [s] unix timestamp, inclusive
+    pub end: i64,   // [s] unix timestamp, exclusive
+    pub frames: u64,
+    pub numeric: BTreeMap<String, NumericStats>,
+    pub enums: BTreeMap<String, EnumStats>,
+}
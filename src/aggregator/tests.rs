@@ -0,0 +1,83 @@
+use super::*;
+use crate::test_utils::{data_from, frame};
+
+#[test]
+fn aggregate_test() {
+    let mut aggregator = Aggregator::new(Duration::from_secs(60));
+
+    assert_eq!(aggregator.push(&frame(120, &[("V", "12000"), ("CS", "3"), ("ERR", "0")])), None);
+    assert_eq!(aggregator.push(&frame(130, &[("V", "13000"), ("CS", "5")])), None);
+    assert_eq!(aggregator.push(&frame(140, &[("V", "14000"), ("CS", "5"), ("PID", "0xA053")])), None);
+    assert_eq!(aggregator.push(&frame(150, &[("V", "12500"), ("CS", "3")])), None);
+
+    let stats = aggregator.push(&frame(185, &[("V", "12000")])).unwrap();
+
+    assert_eq!(stats.start, 120);
+    assert_eq!(stats.end, 180);
+    assert_eq!(stats.frames, 4);
+    assert_eq!(
+        stats.numeric["V"],
+        NumericStats {
+            min: Some(12.0),
+            max: Some(14.0),
+            mean: Some(12.875),
+            last: Some(12.5),
+            count: 4,
+            non_finite: 0
+        }
+    );
+    assert!(!stats.numeric.contains_key("Time"));
+    assert_eq!(stats.enums["CS"].mode, serde_json::json!("Bulk"));
+    assert_eq!(stats.enums["CS"].last, serde_json::json!("Bulk"));
+    assert_eq!(stats.enums["CS"].count, 4);
+    assert_eq!(stats.enums["ERR"].count, 1);
+    assert!(!stats.enums.contains_key("PID"));
+
+    let stats = aggregator.flush().unwrap();
+    assert_eq!(stats.start, 180);
+    assert_eq!(stats.frames, 1);
+    assert_eq!(aggregator.flush(), None);
+}
+
+#[test]
+fn skipped_frames_test() {
+    let mut aggregator = Aggregator::new(Duration::from_secs(60));
+
+    aggregator.push(&frame(600, &[("PPV", "10")]));
+    assert_eq!(aggregator.push(&frame(599, &[("PPV", "1000")])), None);
+    assert_eq!(aggregator.push(&data_from(&[])), None);
+
+    let stats = aggregator.flush().unwrap();
+    assert_eq!(stats.frames, 1);
+    assert_eq!(stats.numeric["PPV"].max.unwrap(), 10.0);
+    assert_eq!(serde_json::to_value(&stats).unwrap()["numeric"]["PPV"]["mean"], 10.0);
+}
+
+#[test]
+fn infinite_time_to_go_test() {
+    let mut aggregator = Aggregator::new(Duration::from_secs(60));
+
+    aggregator.push(&frame(0, &[("TTG", "-1")]));
+    aggregator.push(&frame(10, &[("TTG", "120")]));
+    aggregator.push(&frame(20, &[("TTG", "-1")]));
+    aggregator.push(&frame(30, &[("TTG", "60")]));
+    aggregator.push(&frame(40, &[("SOC", "1000"), ("TTG", "-1")]));
+
+    let stats = aggregator.flush().unwrap();
+    let ttg = &stats.numeric["TTG"];
+    assert_eq!(ttg.max.unwrap(), 7200.0);
+    assert_eq!(ttg.mean.unwrap(), 5400.0);
+    assert_eq!(ttg.last.unwrap(), 3600.0);
+    assert_eq!(ttg.count, 2);
+    assert_eq!(ttg.non_finite, 3);
+
+    let json = serde_json::to_string(&stats).unwrap();
+    assert_eq!(serde_json::from_str::<WindowStats>(&json).unwrap(), stats);
+
+    aggregator.push(&frame(60, &[("TTG", "-1")]));
+    let stats = aggregator.flush().unwrap();
+    assert_eq!(stats.numeric["TTG"].mean, None);
+    assert_eq!(stats.numeric["TTG"].non_finite, 1);
+    let json = serde_json::to_string(&stats).unwrap();
+    assert_eq!(serde_json::from_str::<WindowStats>(&json).unwrap(), stats);
+}
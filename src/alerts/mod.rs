@@ -0,0 +1,236 @@
+//! # Example
+//! ```
+//! let mut engine = AlertEngine::from_json(r#"{"rules": [
+//!     {"name": "low battery", "label": "V", "condition": {"below": 11.8}, "duration": 300},
+//!     {"name": "night drain", "all": [{"label": "PPV", "condition": {"below": 1}}, {"label": "I", "condition": {"below": -5}}]}
+//! ]}"#)
+//!     .unwrap()
+//!     .with_builtin_rules();
+//! // converted variable from converter output
+//! for event in engine.evaluate(&converted).unwrap() {
+//!     println!("{:?}", event);
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Labels, VEDirectData};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind, Result};
+use std::str::FromStr;
+
+/// rules raising alerts reported by device itself: Alarm, AR, WARN and ERR
+pub fn builtin_rules() -> Vec<Rule> {
+    let rule = |name: &str, label: &str, condition: Condition, severity: Severity| Rule {
+        name: name.to_string(),
+        expression: Expression::Field {
+            label: label.to_string(),
+            condition,
+        },
+        severity,
+        duration: 0,
+        hysteresis: 0_f64,
+    };
+    vec![
+        rule("alarm", "Alarm", Condition::Equals(json!("On")), Severity::Critical),
+        rule("alarm reason", "AR", Condition::NotEquals(json!(["None"])), Severity::Critical),
+        rule("warning reason", "WARN", Condition::NotEquals(json!(["None"])), Severity::Warning),
+        rule("error", "ERR", Condition::NotEquals(json!("None")), Severity::Critical),
+    ]
+}
+
+/// Expression with labels resolved
+#[derive(PartialEq, Debug, Clone)]
+enum Compiled {
+    Field(Labels, Condition),
+    All(Vec<Compiled>),
+    Any(Vec<Compiled>),
+}
+
+impl Compiled {
+    fn new(expression: &Expression) -> Result<Self> {
+        let compile_all = |expressions: &[Expression]| -> Result<Vec<Compiled>> {
+            if expressions.is_empty() {
+                return Err(Error::new(ErrorKind::InvalidData, "empty combination of conditions"));
+            }
+            expressions.iter().map(Compiled::new).collect()
+        };
+        match expression {
+            Expression::Field { label, condition } => {
+                let label = Labels::from_str(label).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+                Ok(Compiled::Field(label, condition.clone()))
+            }
+            Expression::All { all } => Ok(Compiled::All(compile_all(all)?)),
+            Expression::Any { any } => Ok(Compiled::Any(compile_all(any)?)),
+        }
+    }
+
+    /// whether expression holds, None when a label it depends on is missing in record
+    fn holds(&self, data: &VEDirectData, hysteresis: f64) -> Result<Option<bool>> {
+        match self {
+            Compiled::Field(label, condition) => {
+                if !data.has_value(*label) {
+                    return Ok(None);
+                }
+                Ok(Some(holds(condition, &data.json_value(*label)?, data.numeric(*label), hysteresis)))
+            }
+            // false of any part decides even when other parts are missing
+            Compiled::All(all) => {
+                let mut result = Some(true);
+                for expression in all {
+                    match expression.holds(data, hysteresis)? {
+                        Some(false) => return Ok(Some(false)),
+                        None => result = None,
+                        Some(true) => {}
+                    }
+                }
+                Ok(result)
+            }
+            Compiled::Any(any) => {
+                let mut result = Some(false);
+                for expression in any {
+                    match expression.holds(data, hysteresis)? {
+                        Some(true) => return Ok(Some(true)),
+                        None => result = None,
+                        Some(false) => {}
+                    }
+                }
+                Ok(result)
+            }
+        }
+    }
+
+    fn labels(&self, labels: &mut Vec<Labels>) {
+        match self {
+            Compiled::Field(label, _) => labels.push(*label),
+            Compiled::All(expressions) | Compiled::Any(expressions) => {
+                expressions.iter().for_each(|expression| expression.labels(labels))
+            }
+        }
+    }
+
+    /// values of referenced fields present in record
+    fn values(&self, data: &VEDirectData) -> Result<BTreeMap<String, Value>> {
+        let mut labels = Vec::new();
+        self.labels(&mut labels);
+        let mut values = BTreeMap::new();
+        for label in labels.into_iter().filter(|label| data.has_value(*label)) {
+            values.insert(label.to_string(), data.json_value(label)?);
+        }
+        Ok(values)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+struct RuleState {
+    pending_since: Option<i64>,
+    active: bool,
+}
+
+/// Evaluates rules over successive records and emits raise and clear events
+#[derive(PartialEq, Debug, Clone)]
+pub struct AlertEngine {
+    rules: Vec<(Rule, Compiled, RuleState)>,
+}
+
+impl AlertEngine {
+    /// fails with InvalidData when rule refers to label unknown to converter or combines no conditions
+    pub fn new(rules: Vec<Rule>) -> Result<Self> {
+        let mut engine = Self { rules: Vec::new() };
+        for rule in rules {
+            engine.add(rule)?;
+        }
+        Ok(engine)
+    }
+
+    pub fn from_json(rules: &str) -> Result<Self> {
+        let set: RuleSet = serde_json::from_str(rules).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Self::new(set.rules)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(rules: &str) -> Result<Self> {
+        let set: RuleSet = toml::from_str(rules).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Self::new(set.rules)
+    }
+
+    pub fn with_builtin_rules(mut self) -> Self {
+        for rule in builtin_rules() {
+            self.add(rule).expect("built-in rules refer to known labels");
+        }
+        self
+    }
+
+    pub fn add(&mut self, rule: Rule) -> Result<()> {
+        let compiled = Compiled::new(&rule.expression)?;
+        self.rules.push((rule, compiled, RuleState::default()));
+        Ok(())
+    }
+
+    /// rules currently raised
+    pub fn active(&self) -> Vec<&Rule> {
+        self.rules
+            .iter()
+            .filter(|(_, _, state)| state.active)
+            .map(|(rule, _, _)| rule)
+            .collect()
+    }
+
+    /// evaluates all rules, rules whose outcome depends on label missing in record keep their state
+    pub fn evaluate(&mut self, data: &VEDirectData) -> Result<Vec<AlertEvent>> {
+        let mut events = Vec::new();
+
+        for (rule, compiled, state) in self.rules.iter_mut() {
+            if !state.active {
+                match compiled.holds(data, 0_f64)? {
+                    Some(true) => {}
+                    Some(false) => {
+                        state.pending_since = None;
+                        continue;
+                    }
+                    None => continue,
+                }
+                let raise = match (rule.duration, data.Time) {
+                    (0, _) => true,
+                    (duration, Some(time)) => time - *state.pending_since.get_or_insert(time) >= duration as i64,
+                    (_, None) => false,
+                };
+                if raise {
+                    state.active = true;
+                    state.pending_since = None;
+                    events.push(AlertEvent::Raised {
+                        rule: rule.name.clone(),
+                        severity: rule.severity,
+                        values: compiled.values(data)?,
+                        time: data.Time,
+                    });
+                }
+            } else if compiled.holds(data, rule.hysteresis)? == Some(false) {
+                state.active = false;
+                events.push(AlertEvent::Cleared {
+                    rule: rule.name.clone(),
+                    severity: rule.severity,
+                    values: compiled.values(data)?,
+                    time: data.Time,
+                });
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// true when condition holds, numeric thresholds are moved by hysteresis towards the clear side
+fn holds(condition: &Condition, value: &Value, numeric: Option<f64>, hysteresis: f64) -> bool {
+    match (condition, numeric) {
+        (Condition::Below(threshold), Some(numeric)) => numeric < threshold + hysteresis,
+        (Condition::Above(threshold), Some(numeric)) => numeric > threshold - hysteresis,
+        (Condition::Below(_), None) | (Condition::Above(_), None) => false,
+        (Condition::Equals(expected), _) => value == expected,
+        (Condition::NotEquals(expected), _) => value != expected,
+    }
+}
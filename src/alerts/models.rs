@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize, Default, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    #[default]
+    Warning,
+    Critical,
+}
+
+/// Condition on value of label, numeric conditions compare normalised unit
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    Below(f64),
+    Above(f64),
+    Equals(serde_json::Value),    // JSON representation of field, e.g. "On" or ["None"]
+    NotEquals(serde_json::Value), // JSON representation of field
+}
+
+/// Condition on single label or combination of expressions which all or any have to hold
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Expression {
+    Field {
+        label: String, // label as sent by device
+        condition: Condition,
+    },
+    All {
+        all: Vec<Expression>,
+    },
+    Any {
+        any: Vec<Expression>,
+    },
+}
+
+/// Alerting rule, e.g. in TOML:
+/// ```toml
+/// [[rules]]
+/// name = "low battery"
+/// label = "V"
+/// condition = { below = 11.8 }
+/// duration = 300
+/// hysteresis = 0.2
+/// severity = "Critical"
+///
+/// [[rules]]
+/// name = "discharging at night"
+/// all = [{ label = "PPV", condition = { below = 1.0 } }, { label = "I", condition = { below = -5.0 } }]
+/// ```
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Rule {
+    pub name: String,
+    #[serde(flatten)]
+    pub expression: Expression,
+    #[serde(default)]
+    pub severity: Severity,
+    #[serde(default)]
+    pub duration: u64, // [s] condition has to hold this long before alert is raised
+    #[serde(default)]
+    pub hysteresis: f64, // numeric condition clears once value is this far back past threshold
+}
+
+/// Set of rules as stored in JSON or TOML file
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleSet {
+    pub rules: Vec<Rule>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub enum AlertEvent {
+    Raised {
+        rule: String,
+        severity: Severity,
+        values: BTreeMap<String, serde_json::Value>, // fields referenced by rule, keyed by label as sent by device
+        time: Option<i64>,
+    },
+    Cleared {
+        rule: String,
+        severity: Severity,
+        values: BTreeMap<String, serde_json::Value>,
+        time: Option<i64>,
+    },
+}
@@ -0,0 +1,136 @@
+use super::*;
+use crate::test_utils::frame;
+
+const RULES: &str = r#"{"rules": [
+    {"name": "low battery", "label": "V", "condition": {"below": 11.8}, "duration": 300, "hysteresis": 0.2, "severity": "Critical"}
+]}"#;
+
+#[test]
+fn duration_and_hysteresis_test() {
+    let mut engine = AlertEngine::from_json(RULES).unwrap();
+
+    assert!(engine.evaluate(&frame(0, &[("V", "11700")])).unwrap().is_empty());
+    assert!(engine.evaluate(&frame(200, &[("V", "11600")])).unwrap().is_empty());
+    let events = engine.evaluate(&frame(300, &[("V", "11600")])).unwrap();
+    assert_eq!(
+        events,
+        vec![AlertEvent::Raised {
+            rule: "low battery".to_string(),
+            severity: Severity::Critical,
+            values: BTreeMap::from([("V".to_string(), serde_json::json!(11.6))]),
+            time: Some(300)
+        }]
+    );
+    assert_eq!(engine.active().len(), 1);
+
+    // missing label keeps state, value within hysteresis does not clear
+    assert!(engine.evaluate(&frame(310, &[("PPV", "10")])).unwrap().is_empty());
+    assert!(engine.evaluate(&frame(320, &[("V", "11900")])).unwrap().is_empty());
+    assert!(matches!(engine.evaluate(&frame(330, &[("V", "12000")])).unwrap()[..], [AlertEvent::Cleared { .. }]));
+    assert!(engine.active().is_empty());
+
+    // interrupted condition restarts duration
+    engine.evaluate(&frame(400, &[("V", "11700")])).unwrap();
+    engine.evaluate(&frame(500, &[("V", "12000")])).unwrap();
+    assert!(engine.evaluate(&frame(710, &[("V", "11700")])).unwrap().is_empty());
+}
+
+#[test]
+fn builtin_rules_test() {
+    let mut engine = AlertEngine::new(vec![]).unwrap().with_builtin_rules();
+
+    assert!(engine
+        .evaluate(&frame(0, &[("ERR", "0"), ("AR", "0"), ("Alarm", "OFF")]))
+        .unwrap()
+        .is_empty());
+    let events = engine
+        .evaluate(&frame(1, &[("ERR", "2"), ("AR", "1"), ("Alarm", "ON")]))
+        .unwrap();
+    let raised: Vec<&str> = events
+        .iter()
+        .filter_map(|event| match event {
+            AlertEvent::Raised { rule, .. } => Some(rule.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(raised, vec!["alarm", "alarm reason", "error"]);
+
+    let events = engine
+        .evaluate(&frame(2, &[("ERR", "0"), ("AR", "0"), ("Alarm", "OFF")]))
+        .unwrap();
+    assert_eq!(events.len(), 3);
+}
+
+#[test]
+fn combined_conditions_test() {
+    let rules = r#"{"rules": [
+        {"name": "night drain", "all": [
+            {"label": "PPV", "condition": {"below": 1}},
+            {"any": [{"label": "I", "condition": {"below": -5}}, {"label": "IL", "condition": {"above": 10}}]}
+        ], "severity": "Info"}
+    ]}"#;
+    let mut engine = AlertEngine::from_json(rules).unwrap();
+
+    assert!(engine
+        .evaluate(&frame(0, &[("PPV", "0"), ("I", "-1000"), ("IL", "2000")]))
+        .unwrap()
+        .is_empty());
+    // false part decides even when the other one is missing
+    assert!(engine.evaluate(&frame(1, &[("PPV", "100")])).unwrap().is_empty());
+    // unknown outcome keeps state
+    assert!(engine.evaluate(&frame(2, &[("PPV", "0")])).unwrap().is_empty());
+
+    let events = engine.evaluate(&frame(3, &[("PPV", "0"), ("I", "-6000")])).unwrap();
+    assert_eq!(
+        events,
+        vec![AlertEvent::Raised {
+            rule: "night drain".to_string(),
+            severity: Severity::Info,
+            values: BTreeMap::from([
+                ("I".to_string(), serde_json::json!(-6.0)),
+                ("PPV".to_string(), serde_json::json!(0.0))
+            ]),
+            time: Some(3)
+        }]
+    );
+    assert!(engine.evaluate(&frame(4, &[("I", "-1000")])).unwrap().is_empty());
+    assert!(matches!(engine.evaluate(&frame(5, &[("PPV", "50")])).unwrap()[..], [AlertEvent::Cleared { .. }]));
+}
+
+#[test]
+fn invalid_rules_test() {
+    let rules = r#"{"rules": [{"name": "x", "label": "Bleble", "condition": {"above": 1}}]}"#;
+    assert_eq!(AlertEngine::from_json(rules).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(AlertEngine::from_json("{").unwrap_err().kind(), ErrorKind::InvalidData);
+    let rules = r#"{"rules": [{"name": "x", "any": []}]}"#;
+    assert_eq!(AlertEngine::from_json(rules).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_rules_test() {
+    let rules = r#"
+        [[rules]]
+        name = "panel overvoltage"
+        label = "VPV"
+        condition = { above = 95.0 }
+
+        [[rules]]
+        name = "bulk"
+        label = "CS"
+        condition = { equals = "Bulk" }
+        severity = "Info"
+
+        [[rules]]
+        name = "charging at night"
+        any = [{ label = "CS", condition = { equals = "Absorption" } }, { label = "I", condition = { above = 100.0 } }]
+    "#;
+    let mut engine = AlertEngine::from_toml(rules).unwrap();
+
+    let events = engine.evaluate(&frame(0, &[("VPV", "96000"), ("CS", "3")])).unwrap();
+    assert_eq!(events.len(), 2);
+    let events = engine.evaluate(&frame(1, &[("CS", "4")])).unwrap();
+    assert!(
+        matches!(&events[..], [AlertEvent::Cleared { rule, .. }, AlertEvent::Raised { rule: raised, .. }] if rule == "bulk" && raised == "charging at night")
+    );
+}
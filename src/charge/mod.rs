@@ -0,0 +1,174 @@
+//! # Example
+//! ```
+//! let mut tracker = ChargeTracker::new(ChargeTrackerConfig::default());
+//! // converted variable from converter output of solar charger, Time is set by parser
+//! for event in tracker.update(&converted) {
+//!     println!("{:?}", event);
+//! }
+//! // days are kept until pruned
+//! tracker.prune_before(today - chrono::Duration::days(30));
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{StateOfOperation, VEDirectData};
+use crate::history::local_date;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+const DAY: i64 = 86400; // [s]
+
+/// Tracks transitions of charger state (CS) and time spent per phase per day, days are local to `config.offset`
+#[derive(PartialEq, Debug, Clone)]
+pub struct ChargeTracker {
+    config: ChargeTrackerConfig,
+    state: Option<(StateOfOperation, i64)>, // current state and time it was entered
+    last_time: Option<i64>,
+    current_day: Option<NaiveDate>,
+    days: BTreeMap<NaiveDate, ChargeDay>,
+}
+
+impl ChargeTracker {
+    pub fn new(config: ChargeTrackerConfig) -> Self {
+        Self {
+            config,
+            state: None,
+            last_time: None,
+            current_day: None,
+            days: BTreeMap::new(),
+        }
+    }
+
+    /// processes frame, frames without Time or CS and frames older than previous one are skipped
+    pub fn update(&mut self, data: &VEDirectData) -> Vec<ChargeEvent> {
+        let mut events = Vec::new();
+        let (time, state) = match (data.Time, data.CS.as_ref()) {
+            (Some(time), Some(state)) => (time, state),
+            _ => return events,
+        };
+        if matches!(self.last_time, Some(last) if time < last) {
+            return events;
+        }
+
+        self.account(time);
+        self.roll_over(time, &mut events);
+
+        let changed = !matches!(&self.state, Some((current, _)) if current == state);
+        if changed {
+            let previous_phase = self.state.as_ref().map(|(previous, _)| ChargePhase::from(previous));
+            if let Some((previous, since)) = self.state.take() {
+                events.push(ChargeEvent::Left {
+                    state: previous,
+                    time,
+                    duration: Duration::from_secs((time - since).max(0) as u64),
+                });
+            }
+            events.push(ChargeEvent::Entered {
+                state: state.clone(),
+                time,
+            });
+            self.enter(state, previous_phase, time, &mut events);
+            self.state = Some((state.clone(), time));
+        }
+
+        self.last_time = Some(time);
+        events
+    }
+
+    /// credits interval since previous frame to phase of current state, split at midnight
+    fn account(&mut self, time: i64) {
+        let (phase, mut from) = match (&self.state, self.last_time) {
+            (Some((state, _)), Some(last)) if time - last <= self.config.max_gap.as_secs() as i64 => {
+                (ChargePhase::from(state), last)
+            }
+            _ => return,
+        };
+        let offset = self.config.offset.local_minus_utc() as i64;
+        while from < time {
+            let midnight = from - (from + offset).rem_euclid(DAY) + DAY;
+            let to = time.min(midnight);
+            if let Some(date) = self.date_of(from) {
+                self.day(date).add(phase, Duration::from_secs((to - from) as u64));
+            }
+            from = to;
+        }
+    }
+
+    /// finishes days before the day of `time`, reporting days which charged without reaching float
+    fn roll_over(&mut self, time: i64, events: &mut Vec<ChargeEvent>) {
+        let today = match self.date_of(time) {
+            Some(today) => today,
+            None => return,
+        };
+        if let Some(previous) = self.current_day {
+            for (date, day) in self.days.range(previous..today) {
+                if day.charged() && !day.reached_float {
+                    events.push(ChargeEvent::Anomaly(ChargeAnomaly::NoFloat { date: *date }));
+                }
+            }
+        }
+        self.current_day = Some(today);
+        // phase entered before midnight and still active counts as reached today
+        if let Some((state, _)) = &self.state {
+            if ChargePhase::from(state) == ChargePhase::Float {
+                self.day(today).reached_float = true;
+            }
+        }
+    }
+
+    fn enter(&mut self, state: &StateOfOperation, previous: Option<ChargePhase>, time: i64, events: &mut Vec<ChargeEvent>) {
+        let date = match self.date_of(time) {
+            Some(date) => date,
+            None => return,
+        };
+        let was_absorption = previous == Some(ChargePhase::Absorption);
+        let max_absorptions = self.config.max_absorptions;
+        let day = self.day(date);
+        match ChargePhase::from(state) {
+            ChargePhase::Float => day.reached_float = true,
+            // device reporting repeated absorption has entered absorption again
+            ChargePhase::Absorption if !was_absorption || *state == StateOfOperation::RepeatedAbsorption => {
+                day.absorption_entries += 1;
+                if day.absorption_entries > max_absorptions {
+                    events.push(ChargeEvent::Anomaly(ChargeAnomaly::RepeatedAbsorption {
+                        date,
+                        count: day.absorption_entries,
+                    }));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn date_of(&self, time: i64) -> Option<NaiveDate> {
+        local_date(time, &self.config.offset)
+    }
+
+    fn day(&mut self, date: NaiveDate) -> &mut ChargeDay {
+        self.days.entry(date).or_insert_with(|| ChargeDay::new(date))
+    }
+
+    /// current state and time since it was entered
+    pub fn state(&self) -> Option<(&StateOfOperation, i64)> {
+        self.state.as_ref().map(|(state, since)| (state, *since))
+    }
+
+    pub fn days(&self) -> &BTreeMap<NaiveDate, ChargeDay> {
+        &self.days
+    }
+
+    pub fn day_of(&self, date: NaiveDate) -> Option<&ChargeDay> {
+        self.days.get(&date)
+    }
+
+    /// removes days before `date` and returns them, e.g. after persisting them on a long running logger
+    pub fn prune_before(&mut self, date: NaiveDate) -> BTreeMap<NaiveDate, ChargeDay> {
+        let kept = self.days.split_off(&date);
+        std::mem::replace(&mut self.days, kept)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::converter::models::StateOfOperation;
+use chrono::{FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Charge phase time is accounted to
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ChargePhase {
+    Bulk,
+    Absorption, // Absorption and RepeatedAbsorption, entering the latter counts as another absorption
+    Float,      // Float and Storage
+    Fault,
+    Other,
+}
+
+impl From<&StateOfOperation> for ChargePhase {
+    fn from(state: &StateOfOperation) -> Self {
+        match state {
+            StateOfOperation::Bulk => ChargePhase::Bulk,
+            StateOfOperation::Absorption | StateOfOperation::RepeatedAbsorption => ChargePhase::Absorption,
+            StateOfOperation::Float | StateOfOperation::Storage => ChargePhase::Float,
+            StateOfOperation::Fault => ChargePhase::Fault,
+            _ => ChargePhase::Other,
+        }
+    }
+}
+
+/// Time spent per phase during a local day of the installation
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct ChargeDay {
+    pub date: NaiveDate,
+    pub bulk: Duration,
+    pub absorption: Duration,
+    pub float: Duration,
+    pub fault: Duration,
+    pub other: Duration,
+    pub absorption_entries: u32,
+    pub reached_float: bool,
+}
+
+impl ChargeDay {
+    pub fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            bulk: Duration::ZERO,
+            absorption: Duration::ZERO,
+            float: Duration::ZERO,
+            fault: Duration::ZERO,
+            other: Duration::ZERO,
+            absorption_entries: 0,
+            reached_float: false,
+        }
+    }
+
+    pub fn duration(&self, phase: ChargePhase) -> Duration {
+        match phase {
+            ChargePhase::Bulk => self.bulk,
+            ChargePhase::Absorption => self.absorption,
+            ChargePhase::Float => self.float,
+            ChargePhase::Fault => self.fault,
+            ChargePhase::Other => self.other,
+        }
+    }
+
+    pub(crate) fn add(&mut self, phase: ChargePhase, duration: Duration) {
+        match phase {
+            ChargePhase::Bulk => self.bulk += duration,
+            ChargePhase::Absorption => self.absorption += duration,
+            ChargePhase::Float => self.float += duration,
+            ChargePhase::Fault => self.fault += duration,
+            ChargePhase::Other => self.other += duration,
+        }
+    }
+
+    /// charger was charging (bulk or absorption) during the day
+    pub fn charged(&self) -> bool {
+        !self.bulk.is_zero() || !self.absorption.is_zero()
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub enum ChargeAnomaly {
+    NoFloat { date: NaiveDate },                        // day with charging that never reached float
+    RepeatedAbsorption { date: NaiveDate, count: u32 }, // absorption entered more often than allowed per day
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub enum ChargeEvent {
+    Entered {
+        state: StateOfOperation,
+        time: i64,
+    },
+    Left {
+        state: StateOfOperation,
+        time: i64,
+        duration: Duration,
+    },
+    Anomaly(ChargeAnomaly),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ChargeTrackerConfig {
+    pub max_gap: Duration,    // intervals between frames longer than this are not accounted
+    pub max_absorptions: u32, // absorption entries per day above this are reported as anomaly
+    pub offset: FixedOffset,  // timezone of the installation, days end at local midnight
+}
+
+impl Default for ChargeTrackerConfig {
+    fn default() -> Self {
+        Self {
+            max_gap: Duration::from_secs(60),
+            max_absorptions: 1,
+            offset: FixedOffset::east_opt(0).unwrap(),
+        }
+    }
+}
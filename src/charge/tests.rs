@@ -0,0 +1,128 @@
+use super::*;
+use crate::test_utils::frame;
+use chrono::FixedOffset;
+
+const MIDNIGHT: i64 = 1_660_003_200; // 2022-08-09 00:00:00 UTC
+
+fn cs(time: i64, cs: &str) -> VEDirectData {
+    frame(time, &[("CS", cs)])
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn transitions_test() {
+    let mut tracker = ChargeTracker::new(ChargeTrackerConfig::default());
+    let start = MIDNIGHT + 8 * 3600;
+
+    assert_eq!(
+        tracker.update(&cs(start, "3")),
+        vec![ChargeEvent::Entered {
+            state: StateOfOperation::Bulk,
+            time: start
+        }]
+    );
+    assert!(tracker.update(&cs(start + 30, "3")).is_empty());
+    assert_eq!(
+        tracker.update(&cs(start + 60, "4")),
+        vec![
+            ChargeEvent::Left {
+                state: StateOfOperation::Bulk,
+                time: start + 60,
+                duration: Duration::from_secs(60)
+            },
+            ChargeEvent::Entered {
+                state: StateOfOperation::Absorption,
+                time: start + 60
+            }
+        ]
+    );
+    tracker.update(&cs(start + 90, "5"));
+    tracker.update(&cs(start + 100, "5"));
+    // frames older than previous one are skipped
+    assert!(tracker.update(&cs(start, "2")).is_empty());
+
+    let day = tracker.day_of(date(2022, 8, 9)).unwrap();
+    assert_eq!(day.bulk, Duration::from_secs(60));
+    assert_eq!(day.absorption, Duration::from_secs(30));
+    assert_eq!(day.float, Duration::from_secs(10));
+    assert_eq!(day.absorption_entries, 1);
+    assert!(day.reached_float);
+    assert_eq!(tracker.state().unwrap(), (&StateOfOperation::Float, start + 90));
+}
+
+#[test]
+fn gap_and_midnight_test() {
+    let mut tracker = ChargeTracker::new(ChargeTrackerConfig::default());
+
+    tracker.update(&cs(MIDNIGHT - 20, "5"));
+    tracker.update(&cs(MIDNIGHT + 30, "5"));
+    tracker.update(&cs(MIDNIGHT + 1000, "5"));
+
+    assert_eq!(tracker.day_of(date(2022, 8, 8)).unwrap().float, Duration::from_secs(20));
+    let today = tracker.day_of(date(2022, 8, 9)).unwrap();
+    assert_eq!(today.float, Duration::from_secs(30));
+    assert!(today.reached_float);
+}
+
+#[test]
+fn local_midnight_test() {
+    let config = ChargeTrackerConfig {
+        offset: FixedOffset::east_opt(2 * 3600).unwrap(),
+        ..ChargeTrackerConfig::default()
+    };
+    let mut tracker = ChargeTracker::new(config);
+    let local_midnight = MIDNIGHT - 2 * 3600;
+
+    tracker.update(&cs(local_midnight - 20, "5"));
+    tracker.update(&cs(local_midnight + 30, "5"));
+
+    assert_eq!(tracker.day_of(date(2022, 8, 8)).unwrap().float, Duration::from_secs(20));
+    assert_eq!(tracker.day_of(date(2022, 8, 9)).unwrap().float, Duration::from_secs(30));
+}
+
+#[test]
+fn anomalies_test() {
+    let mut tracker = ChargeTracker::new(ChargeTrackerConfig::default());
+    let start = MIDNIGHT + 8 * 3600;
+
+    tracker.update(&cs(start, "3"));
+    tracker.update(&cs(start + 60, "4"));
+    tracker.update(&cs(start + 120, "3"));
+    let events = tracker.update(&cs(start + 180, "246"));
+    assert_eq!(
+        events.last().unwrap(),
+        &ChargeEvent::Anomaly(ChargeAnomaly::RepeatedAbsorption {
+            date: date(2022, 8, 9),
+            count: 2
+        })
+    );
+
+    tracker.update(&cs(start + 240, "0"));
+    let events = tracker.update(&cs(MIDNIGHT + 86400 + 60, "0"));
+    assert_eq!(events, vec![ChargeEvent::Anomaly(ChargeAnomaly::NoFloat { date: date(2022, 8, 9) })]);
+
+    let pruned = tracker.prune_before(date(2022, 8, 10));
+    assert_eq!(pruned.keys().collect::<Vec<_>>(), vec![&date(2022, 8, 9)]);
+    assert!(tracker.days().is_empty());
+    assert!(tracker.prune_before(date(2022, 8, 1)).is_empty());
+}
+
+#[test]
+fn repeated_absorption_state_test() {
+    let mut tracker = ChargeTracker::new(ChargeTrackerConfig::default());
+    let start = MIDNIGHT + 8 * 3600;
+
+    tracker.update(&cs(start, "4"));
+    let events = tracker.update(&cs(start + 60, "246"));
+    assert_eq!(
+        events.last().unwrap(),
+        &ChargeEvent::Anomaly(ChargeAnomaly::RepeatedAbsorption {
+            date: date(2022, 8, 9),
+            count: 2
+        })
+    );
+    assert_eq!(tracker.day_of(date(2022, 8, 9)).unwrap().absorption, Duration::from_secs(60));
+}
@@ -0,0 +1,128 @@
+//! Numeric fields in integer units exactly as sent by device, free of floating point representation noise.
+
+use crate::converter::models::Labels;
+use crate::parser::VEDirectParse;
+use serde::{Deserialize, Serialize};
+
+pub type MilliVolt = i64;
+pub type CentiVolt = i64;
+pub type MilliAmpere = i64;
+pub type DeciAmpere = i64;
+pub type MilliAmpereHour = i64;
+pub type CentiKiloWattHour = i64; // 0.01 kWh
+pub type Watt = i64;
+pub type VoltAmpere = i64;
+pub type PerMille = i64;
+pub type DegreeCelsius = i64;
+pub type Minute = i64;
+pub type Second = i64;
+
+#[allow(non_snake_case)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Default)]
+pub struct VEDirectExact {
+    pub V: Option<MilliVolt>,
+    pub VS: Option<MilliVolt>,
+    pub V2: Option<MilliVolt>,
+    pub V3: Option<MilliVolt>,
+    pub VM: Option<MilliVolt>,
+    pub DM: Option<PerMille>,
+    pub VPV: Option<MilliVolt>,
+    pub PPV: Option<Watt>,
+    pub I: Option<MilliAmpere>,
+    pub I2: Option<MilliAmpere>,
+    pub I3: Option<MilliAmpere>,
+    pub IL: Option<MilliAmpere>,
+    pub T: Option<DegreeCelsius>,
+    pub P: Option<Watt>,
+    pub CE: Option<MilliAmpereHour>,
+    pub SOC: Option<PerMille>,
+    pub TTG: Option<Minute>, // -1 when infinite
+    pub H1: Option<MilliAmpereHour>,
+    pub H2: Option<MilliAmpereHour>,
+    pub H3: Option<MilliAmpereHour>,
+    pub H4: Option<i64>,
+    pub H5: Option<i64>,
+    pub H6: Option<MilliAmpereHour>,
+    pub H7: Option<MilliVolt>,
+    pub H8: Option<MilliVolt>,
+    pub H9: Option<Second>,
+    pub H10: Option<i64>,
+    pub H11: Option<i64>,
+    pub H12: Option<i64>,
+    pub H13: Option<i64>,
+    pub H14: Option<i64>,
+    pub H15: Option<MilliVolt>,
+    pub H16: Option<MilliVolt>,
+    pub H17: Option<CentiKiloWattHour>,
+    pub H18: Option<CentiKiloWattHour>,
+    pub H19: Option<CentiKiloWattHour>,
+    pub H20: Option<CentiKiloWattHour>,
+    pub H21: Option<Watt>,
+    pub H22: Option<CentiKiloWattHour>,
+    pub H23: Option<Watt>,
+    pub HSDS: Option<i64>,
+    pub AC_OUT_V: Option<CentiVolt>,
+    pub AC_OUT_I: Option<DeciAmpere>,
+    pub AC_OUT_S: Option<VoltAmpere>,
+    pub DC_IN_V: Option<CentiVolt>,
+    pub DC_IN_I: Option<DeciAmpere>,
+    pub DC_IN_P: Option<Watt>,
+}
+
+fn integer(map: &VEDirectParse, label: Labels) -> Option<i64> {
+    map.get(&label.to_string())
+        .and_then(|value| String::from_utf8_lossy(value).trim().parse::<i64>().ok())
+}
+
+/// extracts numeric fields of parse without scaling, fields which are not integers are None
+pub fn convert_exact(map: &VEDirectParse) -> VEDirectExact {
+    VEDirectExact {
+        V: integer(map, Labels::V),
+        VS: integer(map, Labels::VS),
+        V2: integer(map, Labels::V2),
+        V3: integer(map, Labels::V3),
+        VM: integer(map, Labels::VM),
+        DM: integer(map, Labels::DM),
+        VPV: integer(map, Labels::VPV),
+        PPV: integer(map, Labels::PPV),
+        I: integer(map, Labels::I),
+        I2: integer(map, Labels::I2),
+        I3: integer(map, Labels::I3),
+        IL: integer(map, Labels::IL),
+        T: integer(map, Labels::T),
+        P: integer(map, Labels::P),
+        CE: integer(map, Labels::CE),
+        SOC: integer(map, Labels::SOC),
+        TTG: integer(map, Labels::TTG),
+        H1: integer(map, Labels::H1),
+        H2: integer(map, Labels::H2),
+        H3: integer(map, Labels::H3),
+        H4: integer(map, Labels::H4),
+        H5: integer(map, Labels::H5),
+        H6: integer(map, Labels::H6),
+        H7: integer(map, Labels::H7),
+        H8: integer(map, Labels::H8),
+        H9: integer(map, Labels::H9),
+        H10: integer(map, Labels::H10),
+        H11: integer(map, Labels::H11),
+        H12: integer(map, Labels::H12),
+        H13: integer(map, Labels::H13),
+        H14: integer(map, Labels::H14),
+        H15: integer(map, Labels::H15),
+        H16: integer(map, Labels::H16),
+        H17: integer(map, Labels::H17),
+        H18: integer(map, Labels::H18),
+        H19: integer(map, Labels::H19),
+        H20: integer(map, Labels::H20),
+        H21: integer(map, Labels::H21),
+        H22: integer(map, Labels::H22),
+        H23: integer(map, Labels::H23),
+        HSDS: integer(map, Labels::HSDS),
+        AC_OUT_V: integer(map, Labels::AC_OUT_V),
+        AC_OUT_I: integer(map, Labels::AC_OUT_I),
+        AC_OUT_S: integer(map, Labels::AC_OUT_S),
+        DC_IN_V: integer(map, Labels::DC_IN_V),
+        DC_IN_I: integer(map, Labels::DC_IN_I),
+        DC_IN_P: integer(map, Labels::DC_IN_P),
+    }
+}
@@ -8,7 +8,13 @@
 //! }
 //! ```
 
+pub mod exact;
+#[allow(clippy::derivable_impls)]
 pub mod models;
+#[cfg(feature = "uom")]
+pub mod quantities;
+pub mod registry;
+pub mod v1;
 use self::models::*;
 use crate::parser::VEDirectParse;
 use std::io::Result;
@@ -32,18 +38,22 @@ pub fn convert(mut map: VEDirectParse) -> Result<VEDirectData> {
     Ok(VEDirectData {
         V: convert!(map, Labels::V, convert_mv),
         VS: convert!(map, Labels::VS, convert_mv),
+        V2: convert!(map, Labels::V2, convert_mv),
+        V3: convert!(map, Labels::V3, convert_mv),
         VM: convert!(map, Labels::VM, convert_mv),
-        DM: convert!(map, Labels::DM, convert_parse),
+        DM: convert!(map, Labels::DM, convert_permille),
         VPV: convert!(map, Labels::VPV, convert_mv),
         PPV: convert!(map, Labels::PPV, convert_parse),
         I: convert!(map, Labels::I, convert_ma),
+        I2: convert!(map, Labels::I2, convert_ma),
+        I3: convert!(map, Labels::I3, convert_ma),
         IL: convert!(map, Labels::IL, convert_ma),
         LOAD: convert!(map, Labels::LOAD, convert_load),
         T: convert!(map, Labels::T, convert_parse),
         P: convert!(map, Labels::P, convert_parse),
         CE: convert!(map, Labels::CE, convert_mah),
-        SOC: convert!(map, Labels::SOC, convert_parse),
-        TTG: convert!(map, Labels::TTG, convert_minutes),
+        SOC: convert!(map, Labels::SOC, convert_permille),
+        TTG: convert!(map, Labels::TTG, convert_time_to_go),
         Alarm: convert!(map, Labels::Alarm, convert_alarm),
         Relay: convert!(map, Labels::Relay, convert_relay),
         AR: convert!(map, Labels::AR, convert_alarm_reason),
@@ -56,7 +66,7 @@ pub fn convert(mut map: VEDirectParse) -> Result<VEDirectData> {
         H6: convert!(map, Labels::H6, convert_mah),
         H7: convert!(map, Labels::H7, convert_mv),
         H8: convert!(map, Labels::H8, convert_mv),
-        H9: convert!(map, Labels::H9, convert_parse),
+        H9: convert!(map, Labels::H9, convert_seconds),
         H10: convert!(map, Labels::H10, convert_parse),
         H11: convert!(map, Labels::H11, convert_parse),
         H12: convert!(map, Labels::H12, convert_parse),
@@ -80,9 +90,14 @@ pub fn convert(mut map: VEDirectParse) -> Result<VEDirectData> {
         SER: convert!(map, Labels::SER, convert_none),
         HSDS: convert!(map, Labels::HSDS, convert_parse),
         MODE: convert!(map, Labels::MODE, convert_device_mode),
+        MPPT: convert!(map, Labels::MPPT, convert_tracker_mode),
+        MON: convert!(map, Labels::MON, convert_monitor_mode),
         AC_OUT_V: convert!(map, Labels::AC_OUT_V, convert_cv),
         AC_OUT_I: convert!(map, Labels::AC_OUT_I, convert_da),
         AC_OUT_S: convert!(map, Labels::AC_OUT_S, convert_parse),
+        DC_IN_V: convert!(map, Labels::DC_IN_V, convert_cv),
+        DC_IN_I: convert!(map, Labels::DC_IN_I, convert_da),
+        DC_IN_P: convert!(map, Labels::DC_IN_P, convert_parse),
         WARN: convert!(map, Labels::WARN, convert_warning_reason),
         Calc_sum: if let Some((_key, value)) = map.remove_entry(&Labels::Calc_sum.to_string()) {
             if !value.is_empty() {
@@ -106,13 +121,102 @@ pub fn convert(mut map: VEDirectParse) -> Result<VEDirectData> {
         CAP_BLE: convert!(map, Labels::CAP_BLE, convert_capble),
         Time: convert!(map, Labels::Time, convert_parse),
         Unknown: if !map.is_empty() {
-            let mut vec = Vec::<String>::new();
-            for (key, value) in map {
-                vec.push(format!("{}: {}", key, String::from_utf8_lossy(value.as_slice())));
-            }
-            Some(vec)
+            Some(map.into_iter().map(|(key, value)| (key, UnknownValue::from(value))).collect())
         } else {
             None
         },
+        Extensions: None,
     })
 }
+
+macro_rules! revert {
+    ( $map: ident, $field: expr, $disc: path, $fun: ident) => {{
+        if let Some(value) = $field.as_ref() {
+            $map.insert($disc.to_string(), $fun(value).into_bytes());
+        }
+    }};
+}
+
+/// reverts converted object to parse in raw representation sent by device,
+/// values of `Extensions` are skipped as their raw form is owned by registered converters
+pub fn revert(data: &VEDirectData) -> VEDirectParse {
+    let mut map = VEDirectParse::new();
+    revert!(map, data.V, Labels::V, revert_mv);
+    revert!(map, data.VS, Labels::VS, revert_mv);
+    revert!(map, data.V2, Labels::V2, revert_mv);
+    revert!(map, data.V3, Labels::V3, revert_mv);
+    revert!(map, data.VM, Labels::VM, revert_mv);
+    revert!(map, data.DM, Labels::DM, revert_permille);
+    revert!(map, data.VPV, Labels::VPV, revert_mv);
+    revert!(map, data.PPV, Labels::PPV, revert_parse);
+    revert!(map, data.I, Labels::I, revert_ma);
+    revert!(map, data.I2, Labels::I2, revert_ma);
+    revert!(map, data.I3, Labels::I3, revert_ma);
+    revert!(map, data.IL, Labels::IL, revert_ma);
+    revert!(map, data.LOAD, Labels::LOAD, revert_load);
+    revert!(map, data.T, Labels::T, revert_parse);
+    revert!(map, data.P, Labels::P, revert_parse);
+    revert!(map, data.CE, Labels::CE, revert_mah);
+    revert!(map, data.SOC, Labels::SOC, revert_permille);
+    revert!(map, data.TTG, Labels::TTG, revert_time_to_go);
+    revert!(map, data.Alarm, Labels::Alarm, revert_alarm);
+    revert!(map, data.Relay, Labels::Relay, revert_relay);
+    revert!(map, data.AR, Labels::AR, revert_alarm_reason);
+    revert!(map, data.OR, Labels::OR, revert_off_reason);
+    revert!(map, data.H1, Labels::H1, revert_mah);
+    revert!(map, data.H2, Labels::H2, revert_mah);
+    revert!(map, data.H3, Labels::H3, revert_mah);
+    revert!(map, data.H4, Labels::H4, revert_parse);
+    revert!(map, data.H5, Labels::H5, revert_parse);
+    revert!(map, data.H6, Labels::H6, revert_mah);
+    revert!(map, data.H7, Labels::H7, revert_mv);
+    revert!(map, data.H8, Labels::H8, revert_mv);
+    revert!(map, data.H9, Labels::H9, revert_seconds);
+    revert!(map, data.H10, Labels::H10, revert_parse);
+    revert!(map, data.H11, Labels::H11, revert_parse);
+    revert!(map, data.H12, Labels::H12, revert_parse);
+    revert!(map, data.H13, Labels::H13, revert_parse);
+    revert!(map, data.H14, Labels::H14, revert_parse);
+    revert!(map, data.H15, Labels::H15, revert_mv);
+    revert!(map, data.H16, Labels::H16, revert_mv);
+    revert!(map, data.H17, Labels::H17, revert_dawh);
+    revert!(map, data.H18, Labels::H18, revert_dawh);
+    revert!(map, data.H19, Labels::H19, revert_dawh);
+    revert!(map, data.H20, Labels::H20, revert_dawh);
+    revert!(map, data.H21, Labels::H21, revert_parse);
+    revert!(map, data.H22, Labels::H22, revert_dawh);
+    revert!(map, data.H23, Labels::H23, revert_parse);
+    revert!(map, data.ERR, Labels::ERR, revert_error_code);
+    revert!(map, data.CS, Labels::CS, revert_state_of_operation);
+    revert!(map, data.BMV, Labels::BMV, revert_none);
+    revert!(map, data.FW, Labels::FW, revert_none);
+    revert!(map, data.FWE, Labels::FWE, revert_none);
+    revert!(map, data.PID, Labels::PID, revert_none);
+    revert!(map, data.SER, Labels::SER, revert_none);
+    revert!(map, data.HSDS, Labels::HSDS, revert_parse);
+    revert!(map, data.MODE, Labels::MODE, revert_device_mode);
+    revert!(map, data.MPPT, Labels::MPPT, revert_tracker_mode);
+    revert!(map, data.MON, Labels::MON, revert_monitor_mode);
+    revert!(map, data.AC_OUT_V, Labels::AC_OUT_V, revert_cv);
+    revert!(map, data.AC_OUT_I, Labels::AC_OUT_I, revert_da);
+    revert!(map, data.AC_OUT_S, Labels::AC_OUT_S, revert_parse);
+    revert!(map, data.DC_IN_V, Labels::DC_IN_V, revert_cv);
+    revert!(map, data.DC_IN_I, Labels::DC_IN_I, revert_da);
+    revert!(map, data.DC_IN_P, Labels::DC_IN_P, revert_parse);
+    revert!(map, data.WARN, Labels::WARN, revert_warning_reason);
+    revert!(map, data.BLE, Labels::BLE, revert_ble);
+    revert!(map, data.CAP_BLE, Labels::CAP_BLE, revert_capble);
+    revert!(map, data.Time, Labels::Time, revert_parse);
+    if let Some(checksum) = data.Checksum {
+        map.insert(Labels::Checksum.to_string(), vec![checksum]);
+    }
+    if let Some(calc_sum) = data.Calc_sum {
+        map.insert(Labels::Calc_sum.to_string(), vec![calc_sum]);
+    }
+    if let Some(unknown) = data.Unknown.as_ref() {
+        for (key, value) in unknown {
+            map.insert(key.clone(), value.raw.clone());
+        }
+    }
+    map
+}
@@ -2,13 +2,16 @@
 // #![allow(non_camel_case_types)]
 // #![allow(non_snake_case)]
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::io::{Error, ErrorKind, Result};
 use std::str::FromStr;
+use std::time::Duration;
 use strum::IntoEnumIterator;
-use strum_macros::{Display, EnumIter, FromRepr};
+use strum_macros::{Display, EnumIter, EnumString, FromRepr};
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum StateOfOperation {
     Off = 0,
     LowPower = 1,
@@ -33,7 +36,7 @@ impl Default for StateOfOperation {
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum ErrorCode {
     None = 0,
     BatteryVoltageTooHigh = 2,
@@ -130,7 +133,7 @@ impl Default for WarningReason {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum DeviceMode {
     None = 0,
     VE_REG_MODE_INVERTER = 2,
@@ -144,7 +147,7 @@ impl Default for DeviceMode {
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum BluetoothStatus {
     Off = 0,
     On = 1,
@@ -156,7 +159,7 @@ impl Default for BluetoothStatus {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum BluetoothCapBle {
     None = 0,
     BLE_Supports_Switching_Off = 1,
@@ -168,7 +171,7 @@ impl Default for BluetoothCapBle {
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum Load {
     Off = 0,
     On = 1,
@@ -180,7 +183,39 @@ impl Default for Load {
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter, Default)]
+pub enum TrackerMode {
+    #[default]
+    Off = 0,
+    VoltageOrCurrentLimited = 1,
+    MppTrackerActive = 2,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter, Default)]
+#[repr(i32)]
+pub enum MonitorMode {
+    SolarCharger = -9,
+    WindCharger = -8,
+    ShaftGenerator = -7,
+    Alternator = -6,
+    FuelCell = -5,
+    WaterGenerator = -4,
+    DcDcCharger = -3,
+    AcCharger = -2,
+    GenericSource = -1,
+    #[default]
+    BatteryMonitor = 0,
+    GenericLoad = 1,
+    ElectricDrive = 2,
+    Fridge = 3,
+    WaterPump = 4,
+    BilgePump = 5,
+    DcSystem = 6,
+    Inverter = 7,
+    WaterHeater = 8,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum Alarm {
     Off = 0,
     On = 1,
@@ -191,7 +226,7 @@ impl Default for Alarm {
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, FromRepr, EnumIter)]
 pub enum Relay {
     Off = 0,
     On = 1,
@@ -203,15 +238,19 @@ impl Default for Relay {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Display)]
+#[derive(Display, EnumIter, EnumString, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 pub enum Labels {
     V,     //                           [mV] Main (battery) voltage
     VS,    //                           [mV] Auxiliary (starter) voltage
+    V2,    //                           [mV] Main or channel 2 (battery) voltage
+    V3,    //                           [mV] Main or channel 3 (battery) voltage
     VM,    //                           [mV] Mid-point voltage of the battery bank
     DM,    //                           [‰] Mid-point deviation of the battery bank
     VPV,   //                           [mV] Panel voltage
     PPV,   //                           [W] Panel power
     I,     //                           [mA] Battery current
+    I2,    //                           [mA] Channel 2 battery current
+    I3,    //                           [mA] Channel 3 battery current
     IL,    //                           [mA] Load current
     LOAD,  //                           Load output state (ON/OFF)
     T,     //                           [°C] Battery temperature
@@ -256,62 +295,87 @@ pub enum Labels {
     SER, //                             Serial number
     HSDS,  //                           Day sequence number (0..364)
     MODE,  //                           Device mode
+    MPPT,  //                           Tracker operation mode
+    MON,   //                           DC monitor mode
     AC_OUT_V, //                        [0.01 V] AC output voltage
     AC_OUT_I, //                        [0.1 A] AC output current
     AC_OUT_S, //                        [W] AC output power
+    DC_IN_V, //                         [0.01 V] DC input voltage
+    DC_IN_I, //                         [0.1 A] DC input current
+    DC_IN_P, //                         [W] DC input power
     WARN,  //                           Warning reason
     BLE,   //                           Bluetooth status
     CAP_BLE, //                         Bloetooth capabilities
     Checksum, //                        Checksum from packet
     Calc_sum, //                        Checksum calculated by parser
-    Unknown, //                         Unknown labels vector
+    Unknown, //                         Unknown labels map
+    Extensions, //                      Labels converted by registry
     Time,  //                           Timestamp
 }
 
+/// labels are serialized as sent by device (`SER#`)
+impl Serialize for Labels {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Labels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let label = String::deserialize(deserializer)?;
+        Labels::from_str(&label).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Converted packet, units are normalised as noted next to each field
 #[allow(non_snake_case)]
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct VEDirectData {
-    pub V: Option<f64>,
-    pub VS: Option<f64>,
-    pub VM: Option<f64>,
-    pub DM: Option<f64>,
-    pub VPV: Option<f64>,
-    pub PPV: Option<f64>,
-    pub I: Option<f64>,
-    pub IL: Option<f64>,
+    pub V: Option<V>,      // [V]
+    pub VS: Option<V>,     // [V]
+    pub V2: Option<V>,     // [V]
+    pub V3: Option<V>,     // [V]
+    pub VM: Option<V>,     // [V]
+    pub DM: Option<Ratio>, // [0..1]
+    pub VPV: Option<V>,    // [V]
+    pub PPV: Option<W>,    // [W]
+    pub I: Option<A>,      // [A]
+    pub I2: Option<A>,     // [A]
+    pub I3: Option<A>,     // [A]
+    pub IL: Option<A>,     // [A]
     pub LOAD: Option<Load>,
-    pub T: Option<f64>,
-    pub P: Option<f64>,
-    pub CE: Option<f64>,
-    pub SOC: Option<f64>,
-    pub TTG: Option<f64>,
+    pub T: Option<DegC>,    // [°C]
+    pub P: Option<W>,       // [W]
+    pub CE: Option<Ah>,     // [Ah]
+    pub SOC: Option<Ratio>, // [0..1]
+    pub TTG: Option<TimeToGo>,
     pub Alarm: Option<Alarm>,
     pub Relay: Option<Relay>,
     pub AR: Option<Vec<AlarmReason>>,
     pub OR: Option<Vec<OffReason>>,
-    pub H1: Option<f64>,
-    pub H2: Option<f64>,
-    pub H3: Option<f64>,
+    pub H1: Option<Ah>, // [Ah]
+    pub H2: Option<Ah>, // [Ah]
+    pub H3: Option<Ah>, // [Ah]
     pub H4: Option<f64>,
     pub H5: Option<f64>,
-    pub H6: Option<f64>,
-    pub H7: Option<f64>,
-    pub H8: Option<f64>,
-    pub H9: Option<f64>,
+    pub H6: Option<Ah>, // [Ah]
+    pub H7: Option<V>,  // [V]
+    pub H8: Option<V>,  // [V]
+    pub H9: Option<Duration>,
     pub H10: Option<f64>,
     pub H11: Option<f64>,
     pub H12: Option<f64>,
     pub H13: Option<f64>,
     pub H14: Option<f64>,
-    pub H15: Option<f64>,
-    pub H16: Option<f64>,
-    pub H17: Option<f64>,
-    pub H18: Option<f64>,
-    pub H19: Option<f64>,
-    pub H20: Option<f64>,
-    pub H21: Option<f64>,
-    pub H22: Option<f64>,
-    pub H23: Option<f64>,
+    pub H15: Option<V>,  // [V]
+    pub H16: Option<V>,  // [V]
+    pub H17: Option<Wh>, // [Wh]
+    pub H18: Option<Wh>, // [Wh]
+    pub H19: Option<Wh>, // [Wh]
+    pub H20: Option<Wh>, // [Wh]
+    pub H21: Option<W>,  // [W]
+    pub H22: Option<Wh>, // [Wh]
+    pub H23: Option<W>,  // [W]
     pub ERR: Option<ErrorCode>,
     pub CS: Option<StateOfOperation>,
     pub BMV: Option<String>,
@@ -321,22 +385,167 @@ pub struct VEDirectData {
     pub SER: Option<String>,
     pub HSDS: Option<f64>,
     pub MODE: Option<DeviceMode>,
-    pub AC_OUT_V: Option<f64>,
-    pub AC_OUT_I: Option<f64>,
-    pub AC_OUT_S: Option<f64>,
+    pub MPPT: Option<TrackerMode>,
+    pub MON: Option<MonitorMode>,
+    pub AC_OUT_V: Option<V>,   // [V]
+    pub AC_OUT_I: Option<A>,   // [A]
+    pub AC_OUT_S: Option<f64>, // [VA]
+    pub DC_IN_V: Option<V>,    // [V]
+    pub DC_IN_I: Option<A>,    // [A]
+    pub DC_IN_P: Option<W>,    // [W]
     pub WARN: Option<Vec<WarningReason>>,
     pub Calc_sum: Option<u8>,
     pub Checksum: Option<u8>,
     pub BLE: Option<BluetoothStatus>,
     pub CAP_BLE: Option<BluetoothCapBle>,
-    pub Time: Option<i64>,
-    pub Unknown: Option<Vec<String>>,
+    pub Time: Option<i64>, // [s] unix timestamp
+    pub Unknown: Option<BTreeMap<String, UnknownValue>>,
+    pub Extensions: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+impl VEDirectData {
+    /// value converted by registered converter of label
+    pub fn extension<T: DeserializeOwned>(&self, label: &str) -> Option<T> {
+        let value = self.Extensions.as_ref()?.get(label)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// value of numeric label in normalised unit, durations in seconds with infinite time-to-go as `f64::INFINITY`
+    pub fn numeric(&self, label: Labels) -> Option<f64> {
+        match label {
+            Labels::V => self.V,
+            Labels::VS => self.VS,
+            Labels::V2 => self.V2,
+            Labels::V3 => self.V3,
+            Labels::VM => self.VM,
+            Labels::DM => self.DM,
+            Labels::VPV => self.VPV,
+            Labels::PPV => self.PPV,
+            Labels::I => self.I,
+            Labels::I2 => self.I2,
+            Labels::I3 => self.I3,
+            Labels::IL => self.IL,
+            Labels::T => self.T,
+            Labels::P => self.P,
+            Labels::CE => self.CE,
+            Labels::SOC => self.SOC,
+            Labels::TTG => self.TTG.map(|ttg| match ttg {
+                TimeToGo::Infinite => f64::INFINITY,
+                TimeToGo::Remaining(remaining) => remaining.as_secs_f64(),
+            }),
+            Labels::H1 => self.H1,
+            Labels::H2 => self.H2,
+            Labels::H3 => self.H3,
+            Labels::H4 => self.H4,
+            Labels::H5 => self.H5,
+            Labels::H6 => self.H6,
+            Labels::H7 => self.H7,
+            Labels::H8 => self.H8,
+            Labels::H9 => self.H9.map(|h9| h9.as_secs_f64()),
+            Labels::H10 => self.H10,
+            Labels::H11 => self.H11,
+            Labels::H12 => self.H12,
+            Labels::H13 => self.H13,
+            Labels::H14 => self.H14,
+            Labels::H15 => self.H15,
+            Labels::H16 => self.H16,
+            Labels::H17 => self.H17,
+            Labels::H18 => self.H18,
+            Labels::H19 => self.H19,
+            Labels::H20 => self.H20,
+            Labels::H21 => self.H21,
+            Labels::H22 => self.H22,
+            Labels::H23 => self.H23,
+            Labels::HSDS => self.HSDS,
+            Labels::AC_OUT_V => self.AC_OUT_V,
+            Labels::AC_OUT_I => self.AC_OUT_I,
+            Labels::AC_OUT_S => self.AC_OUT_S,
+            Labels::DC_IN_V => self.DC_IN_V,
+            Labels::DC_IN_I => self.DC_IN_I,
+            Labels::DC_IN_P => self.DC_IN_P,
+            Labels::Time => self.Time.map(|time| time as f64),
+            _ => None,
+        }
+    }
+}
+
+/// field accessors by label, each label is stored in field of the same name
+macro_rules! label_fields {
+    ( $( $label: ident ),* ) => {
+        impl VEDirectData {
+            /// true when label has value
+            pub fn has_value(&self, label: Labels) -> bool {
+                match label {
+                    $( Labels::$label => self.$label.is_some(), )*
+                }
+            }
+
+            /// true when label has equal value in both records or is missing in both
+            pub fn same_value(&self, other: &VEDirectData, label: Labels) -> bool {
+                match label {
+                    $( Labels::$label => self.$label == other.$label, )*
+                }
+            }
+
+            /// removes value of label
+            pub fn clear(&mut self, label: Labels) {
+                match label {
+                    $( Labels::$label => self.$label = None, )*
+                }
+            }
+
+            /// sets value of label to the one of `other`
+            pub fn copy_value(&mut self, other: &VEDirectData, label: Labels) {
+                match label {
+                    $( Labels::$label => self.$label = other.$label.clone(), )*
+                }
+            }
+
+            /// value of label in its serialised form, null when missing
+            pub fn json_value(&self, label: Labels) -> serde_json::Result<serde_json::Value> {
+                match label {
+                    $( Labels::$label => serde_json::to_value(&self.$label), )*
+                }
+            }
+        }
+    };
+}
+
+label_fields!(
+    V, VS, V2, V3, VM, DM, VPV, PPV, I, I2, I3, IL, LOAD, T, P, CE, SOC, TTG, Alarm, Relay, AR, OR, H1, H2, H3, H4, H5, H6, H7,
+    H8, H9, H10, H11, H12, H13, H14, H15, H16, H17, H18, H19, H20, H21, H22, H23, ERR, CS, BMV, FW, FWE, PID, SER, HSDS, MODE,
+    MPPT, MON, AC_OUT_V, AC_OUT_I, AC_OUT_S, DC_IN_V, DC_IN_I, DC_IN_P, WARN, Calc_sum, Checksum, BLE, CAP_BLE, Time, Unknown,
+    Extensions
+);
+
+/// Value of label not known to converter
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+pub struct UnknownValue {
+    pub raw: Vec<u8>,
+    pub lossy: String, // raw decoded as UTF-8 with invalid sequences replaced
+}
+
+impl From<Vec<u8>> for UnknownValue {
+    fn from(raw: Vec<u8>) -> Self {
+        let lossy = String::from_utf8_lossy(raw.as_slice()).to_string();
+        Self { raw, lossy }
+    }
+}
+
+/// Time-to-go of battery monitor, device reports -1 when battery is not being discharged
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum TimeToGo {
+    Infinite,
+    Remaining(Duration),
 }
 
 pub type V = f64; // volt
 pub type A = f64; // ampere
 pub type Ah = f64; // ampere hour
 pub type Wh = f64; // watt hour
+pub type W = f64; // watt
+pub type Ratio = f64; // fraction of unity
+pub type DegC = f64; // degree celsius
 
 #[allow(non_camel_case_types)]
 pub type mV = f64; // mili volt
@@ -352,6 +561,7 @@ pub type mAh = f64; // mili ampere hour
 pub type daWh = f64; // deca watt hour
 #[allow(non_camel_case_types)]
 pub type kWh = f64; // kilo watt hour
+pub type Permille = f64; // per mille
 
 pub fn convert_mv(str: String) -> Result<V> {
     let val = str.parse::<mV>().map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
@@ -404,6 +614,29 @@ pub fn convert_minutes(str: String) -> Result<f64> {
     Ok(val * 60_f64)
 }
 
+pub fn convert_permille(str: String) -> Result<Ratio> {
+    let val = str
+        .parse::<Permille>()
+        .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+    Ok(val / 1000_f64)
+}
+
+pub fn convert_seconds(str: String) -> Result<Duration> {
+    let val = str.parse::<u64>().map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+    Ok(Duration::from_secs(val))
+}
+
+pub fn convert_time_to_go(str: String) -> Result<TimeToGo> {
+    let val = str.parse::<f64>().map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+    if val == -1_f64 {
+        Ok(TimeToGo::Infinite)
+    } else if val >= 0_f64 {
+        Ok(TimeToGo::Remaining(Duration::from_secs_f64(val * 60_f64)))
+    } else {
+        Err(Error::from(ErrorKind::InvalidData))
+    }
+}
+
 pub fn convert_state_of_operation(field: String) -> Result<StateOfOperation> {
     match StateOfOperation::from_repr(field.parse::<usize>().map_err(|_| Error::from(ErrorKind::InvalidData))?) {
         Some(v) => Ok(v),
@@ -469,6 +702,20 @@ pub fn convert_device_mode(field: String) -> Result<DeviceMode> {
     }
 }
 
+pub fn convert_tracker_mode(field: String) -> Result<TrackerMode> {
+    match TrackerMode::from_repr(field.parse::<usize>().map_err(|_| Error::from(ErrorKind::InvalidData))?) {
+        Some(v) => Ok(v),
+        None => Err(Error::from(ErrorKind::InvalidData)),
+    }
+}
+
+pub fn convert_monitor_mode(field: String) -> Result<MonitorMode> {
+    match MonitorMode::from_repr(field.parse::<i32>().map_err(|_| Error::from(ErrorKind::InvalidData))?) {
+        Some(v) => Ok(v),
+        None => Err(Error::from(ErrorKind::InvalidData)),
+    }
+}
+
 pub fn convert_off_reason(field: String) -> Result<Vec<OffReason>> {
     let without_prefix = field.trim_start_matches("0x");
     let val = usize::from_str_radix(without_prefix, 16).map_err(|_| Error::from(ErrorKind::InvalidData))?;
@@ -542,3 +789,123 @@ pub fn convert_load(field: String) -> Result<Load> {
         Err(Error::from(ErrorKind::InvalidData))
     }
 }
+
+/// formats scaled value as sent by device, dropping float representation noise
+fn format_raw(val: f64) -> String {
+    ((val * 1e6).round() / 1e6 + 0_f64).to_string()
+}
+
+pub fn revert_mv(val: &V) -> String {
+    format_raw(val * 1000_f64)
+}
+
+pub fn revert_ma(val: &A) -> String {
+    format_raw(val * 1000_f64)
+}
+
+pub fn revert_mah(val: &Ah) -> String {
+    format_raw(val * 1000_f64)
+}
+
+pub fn revert_kwh(val: &Wh) -> String {
+    format_raw(val / 1000_f64)
+}
+
+pub fn revert_dawh(val: &Wh) -> String {
+    format_raw(val / 10_f64)
+}
+
+pub fn revert_cv(val: &V) -> String {
+    format_raw(val * 100_f64)
+}
+
+pub fn revert_da(val: &A) -> String {
+    format_raw(val * 10_f64)
+}
+
+pub fn revert_none(val: &str) -> String {
+    val.to_string()
+}
+
+pub fn revert_parse<T>(val: &T) -> String
+where
+    T: ToString,
+{
+    val.to_string()
+}
+
+pub fn revert_permille(val: &Ratio) -> String {
+    format_raw(val * 1000_f64)
+}
+
+pub fn revert_seconds(val: &Duration) -> String {
+    val.as_secs().to_string()
+}
+
+pub fn revert_time_to_go(val: &TimeToGo) -> String {
+    match val {
+        TimeToGo::Infinite => "-1".to_string(),
+        TimeToGo::Remaining(remaining) => format_raw(remaining.as_secs_f64() / 60_f64),
+    }
+}
+
+pub fn revert_state_of_operation(val: &StateOfOperation) -> String {
+    (val.clone() as usize).to_string()
+}
+
+pub fn revert_error_code(val: &ErrorCode) -> String {
+    (val.clone() as usize).to_string()
+}
+
+pub fn revert_alarm_reason(val: &[AlarmReason]) -> String {
+    val.iter().fold(0, |acc, reason| acc | *reason as usize).to_string()
+}
+
+pub fn revert_warning_reason(val: &[WarningReason]) -> String {
+    val.iter().fold(0, |acc, reason| acc | *reason as usize).to_string()
+}
+
+pub fn revert_device_mode(val: &DeviceMode) -> String {
+    (val.clone() as usize).to_string()
+}
+
+pub fn revert_tracker_mode(val: &TrackerMode) -> String {
+    (val.clone() as usize).to_string()
+}
+
+pub fn revert_monitor_mode(val: &MonitorMode) -> String {
+    (val.clone() as i32).to_string()
+}
+
+pub fn revert_off_reason(val: &[OffReason]) -> String {
+    format!("0x{:08X}", val.iter().fold(0, |acc, reason| acc | *reason as usize))
+}
+
+pub fn revert_ble(val: &BluetoothStatus) -> String {
+    format!("0x{:08X}", val.clone() as usize)
+}
+
+pub fn revert_capble(val: &BluetoothCapBle) -> String {
+    format!("0x{:08X}", val.clone() as usize)
+}
+
+pub fn revert_alarm(val: &Alarm) -> String {
+    match val {
+        Alarm::On => "ON".to_string(),
+        Alarm::Off => "OFF".to_string(),
+    }
+}
+
+pub fn revert_relay(val: &Relay) -> String {
+    match val {
+        Relay::On => "ON".to_string(),
+        Relay::Off => "OFF".to_string(),
+    }
+}
+
+pub fn revert_load(val: &Load) -> String {
+    match val {
+        Load::On => "ON".to_string(),
+        Load::Off => "OFF".to_string(),
+    }
+}
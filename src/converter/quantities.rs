@@ -0,0 +1,130 @@
+//! Physical fields of VEDirectData as uom quantities, available with `uom` feature.
+//! Counters (H4, H5, H10..H14, HSDS) are dimensionless and stay in VEDirectData.
+
+use crate::converter::models::{TimeToGo, VEDirectData};
+use uom::si::electric_charge::ampere_hour;
+use uom::si::electric_current::ampere;
+use uom::si::electric_potential::volt;
+use uom::si::energy::watt_hour;
+use uom::si::f64::{ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature, Time};
+use uom::si::power::watt;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::time::second;
+
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Clone)]
+pub struct VEDirectQuantities {
+    pub V: Option<ElectricPotential>,
+    pub VS: Option<ElectricPotential>,
+    pub V2: Option<ElectricPotential>,
+    pub V3: Option<ElectricPotential>,
+    pub VM: Option<ElectricPotential>,
+    pub DM: Option<Ratio>,
+    pub VPV: Option<ElectricPotential>,
+    pub PPV: Option<Power>,
+    pub I: Option<ElectricCurrent>,
+    pub I2: Option<ElectricCurrent>,
+    pub I3: Option<ElectricCurrent>,
+    pub IL: Option<ElectricCurrent>,
+    pub T: Option<ThermodynamicTemperature>,
+    pub P: Option<Power>,
+    pub CE: Option<ElectricCharge>,
+    pub SOC: Option<Ratio>,
+    pub TTG: Option<Time>, // infinite time-to-go is f64::INFINITY seconds
+    pub H1: Option<ElectricCharge>,
+    pub H2: Option<ElectricCharge>,
+    pub H3: Option<ElectricCharge>,
+    pub H6: Option<ElectricCharge>,
+    pub H7: Option<ElectricPotential>,
+    pub H8: Option<ElectricPotential>,
+    pub H9: Option<Time>,
+    pub H15: Option<ElectricPotential>,
+    pub H16: Option<ElectricPotential>,
+    pub H17: Option<Energy>,
+    pub H18: Option<Energy>,
+    pub H19: Option<Energy>,
+    pub H20: Option<Energy>,
+    pub H21: Option<Power>,
+    pub H22: Option<Energy>,
+    pub H23: Option<Power>,
+    pub AC_OUT_V: Option<ElectricPotential>,
+    pub AC_OUT_I: Option<ElectricCurrent>,
+    pub AC_OUT_S: Option<Power>, // apparent power in VA
+    pub DC_IN_V: Option<ElectricPotential>,
+    pub DC_IN_I: Option<ElectricCurrent>,
+    pub DC_IN_P: Option<Power>,
+}
+
+fn volts(val: Option<f64>) -> Option<ElectricPotential> {
+    val.map(ElectricPotential::new::<volt>)
+}
+
+fn amperes(val: Option<f64>) -> Option<ElectricCurrent> {
+    val.map(ElectricCurrent::new::<ampere>)
+}
+
+fn ampere_hours(val: Option<f64>) -> Option<ElectricCharge> {
+    val.map(ElectricCharge::new::<ampere_hour>)
+}
+
+fn watts(val: Option<f64>) -> Option<Power> {
+    val.map(Power::new::<watt>)
+}
+
+fn watt_hours(val: Option<f64>) -> Option<Energy> {
+    val.map(Energy::new::<watt_hour>)
+}
+
+fn ratios(val: Option<f64>) -> Option<Ratio> {
+    val.map(Ratio::new::<ratio>)
+}
+
+impl From<&VEDirectData> for VEDirectQuantities {
+    fn from(data: &VEDirectData) -> Self {
+        VEDirectQuantities {
+            V: volts(data.V),
+            VS: volts(data.VS),
+            V2: volts(data.V2),
+            V3: volts(data.V3),
+            VM: volts(data.VM),
+            DM: ratios(data.DM),
+            VPV: volts(data.VPV),
+            PPV: watts(data.PPV),
+            I: amperes(data.I),
+            I2: amperes(data.I2),
+            I3: amperes(data.I3),
+            IL: amperes(data.IL),
+            T: data.T.map(ThermodynamicTemperature::new::<degree_celsius>),
+            P: watts(data.P),
+            CE: ampere_hours(data.CE),
+            SOC: ratios(data.SOC),
+            TTG: data.TTG.map(|ttg| match ttg {
+                TimeToGo::Infinite => Time::new::<second>(f64::INFINITY),
+                TimeToGo::Remaining(duration) => Time::new::<second>(duration.as_secs_f64()),
+            }),
+            H1: ampere_hours(data.H1),
+            H2: ampere_hours(data.H2),
+            H3: ampere_hours(data.H3),
+            H6: ampere_hours(data.H6),
+            H7: volts(data.H7),
+            H8: volts(data.H8),
+            H9: data.H9.map(|duration| Time::new::<second>(duration.as_secs_f64())),
+            H15: volts(data.H15),
+            H16: volts(data.H16),
+            H17: watt_hours(data.H17),
+            H18: watt_hours(data.H18),
+            H19: watt_hours(data.H19),
+            H20: watt_hours(data.H20),
+            H21: watts(data.H21),
+            H22: watt_hours(data.H22),
+            H23: watts(data.H23),
+            AC_OUT_V: volts(data.AC_OUT_V),
+            AC_OUT_I: amperes(data.AC_OUT_I),
+            AC_OUT_S: watts(data.AC_OUT_S),
+            DC_IN_V: volts(data.DC_IN_V),
+            DC_IN_I: amperes(data.DC_IN_I),
+            DC_IN_P: watts(data.DC_IN_P),
+        }
+    }
+}
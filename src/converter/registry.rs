@@ -0,0 +1,90 @@
+//! # Example
+//! ```
+//! let mut registry = ConverterRegistry::new();
+//! // custom label sent by modified firmware
+//! registry.register("TBAT", |value: String| value.parse::<f64>().map_err(|e| Error::new(ErrorKind::InvalidData, e)));
+//! let converted = registry.convert(parsed).unwrap();
+//! let tbat: Option<f64> = converted.extension("TBAT");
+//! ```
+
+use super::convert;
+use super::models::*;
+use crate::parser::VEDirectParse;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io::{Error, ErrorKind, Result};
+
+type ConvertFn = Box<dyn Fn(String) -> Result<serde_json::Value> + Send + Sync>;
+
+/// Set of application defined converters applied before the built-in ones.
+/// Labels handled by registry are removed from the parse, so registering a built-in
+/// label overrides it: its typed field stays `None` and the result lands in `Extensions`.
+/// Raw value of label whose converter fails is kept in `Unknown`.
+#[derive(Default)]
+pub struct ConverterRegistry {
+    converters: HashMap<String, ConvertFn>,
+}
+
+impl ConverterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// registers conversion function for label, replacing previously registered one
+    pub fn register<T, F>(&mut self, label: &str, fun: F) -> &mut Self
+    where
+        T: Serialize,
+        F: Fn(String) -> Result<T> + Send + Sync + 'static,
+    {
+        self.converters.insert(
+            label.to_string(),
+            Box::new(move |value| serde_json::to_value(fun(value)?).map_err(|e| Error::new(ErrorKind::InvalidData, e))),
+        );
+        self
+    }
+
+    /// removes converter of label, returns true when it was registered
+    pub fn unregister(&mut self, label: &str) -> bool {
+        self.converters.remove(label).is_some()
+    }
+
+    pub fn contains(&self, label: &str) -> bool {
+        self.converters.contains_key(label)
+    }
+
+    /// converts parse with registered converters first and built-in ones for remaining labels
+    pub fn convert(&self, mut map: VEDirectParse) -> Result<VEDirectData> {
+        let mut extensions = BTreeMap::new();
+        let mut rejected = BTreeMap::new();
+        for (label, fun) in self.converters.iter() {
+            if let Some(value) = map.remove(label) {
+                match fun(String::from_utf8_lossy(value.as_slice()).to_string()) {
+                    Ok(converted) => {
+                        extensions.insert(label.clone(), converted);
+                    }
+                    Err(_) => {
+                        rejected.insert(label.clone(), UnknownValue::from(value));
+                    }
+                }
+            }
+        }
+
+        let mut data = convert(map)?;
+        if !extensions.is_empty() {
+            data.Extensions = Some(extensions);
+        }
+        if !rejected.is_empty() {
+            data.Unknown.get_or_insert_with(BTreeMap::new).append(&mut rejected);
+        }
+        Ok(data)
+    }
+}
+
+impl fmt::Debug for ConverterRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut labels: Vec<&String> = self.converters.keys().collect();
+        labels.sort();
+        f.debug_struct("ConverterRegistry").field("labels", &labels).finish()
+    }
+}
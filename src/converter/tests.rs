@@ -1,6 +1,11 @@
 use super::convert;
+use super::exact::convert_exact;
+use super::registry::ConverterRegistry;
+use super::revert;
+use super::v1::convert_v1;
 use crate::converter::models::*;
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[test]
 fn si_converters_test() {
@@ -10,6 +15,19 @@ fn si_converters_test() {
     assert_eq!(convert_kwh("2000".to_string()).unwrap(), 2000000 as Wh);
 }
 
+#[test]
+fn unit_converters_test() {
+    assert_eq!(convert_permille("1000".to_string()).unwrap(), 1 as Ratio);
+    assert_eq!(convert_permille("-12".to_string()).unwrap(), -0.012 as Ratio);
+    assert_eq!(convert_seconds("3600".to_string()).unwrap(), Duration::from_secs(3600));
+    convert_seconds("-1".to_string()).expect_err("");
+    assert_eq!(convert_time_to_go("-1".to_string()).unwrap(), TimeToGo::Infinite);
+    assert_eq!(convert_time_to_go("90".to_string()).unwrap(), TimeToGo::Remaining(Duration::from_secs(5400)));
+    assert_eq!(convert_time_to_go("0".to_string()).unwrap(), TimeToGo::Remaining(Duration::ZERO));
+    convert_time_to_go("-2".to_string()).expect_err("");
+    convert_time_to_go("STRING".to_string()).expect_err("");
+}
+
 #[test]
 fn state_of_operation_test() {
     assert_eq!(convert_state_of_operation("0".to_string()).unwrap(), StateOfOperation::Off);
@@ -49,6 +67,25 @@ fn convert_device_mode_test() {
     convert_device_mode("-1120".to_string()).expect_err("");
 }
 
+#[test]
+fn convert_tracker_mode_test() {
+    assert_eq!(convert_tracker_mode("0".to_string()).unwrap(), TrackerMode::Off);
+    assert_eq!(convert_tracker_mode("1".to_string()).unwrap(), TrackerMode::VoltageOrCurrentLimited);
+    assert_eq!(convert_tracker_mode("2".to_string()).unwrap(), TrackerMode::MppTrackerActive);
+    convert_tracker_mode("3".to_string()).expect_err("");
+    convert_tracker_mode("-1".to_string()).expect_err("");
+}
+
+#[test]
+fn convert_monitor_mode_test() {
+    assert_eq!(convert_monitor_mode("-9".to_string()).unwrap(), MonitorMode::SolarCharger);
+    assert_eq!(convert_monitor_mode("-1".to_string()).unwrap(), MonitorMode::GenericSource);
+    assert_eq!(convert_monitor_mode("0".to_string()).unwrap(), MonitorMode::BatteryMonitor);
+    assert_eq!(convert_monitor_mode("8".to_string()).unwrap(), MonitorMode::WaterHeater);
+    convert_monitor_mode("9".to_string()).expect_err("");
+    convert_monitor_mode("-10".to_string()).expect_err("");
+}
+
 #[test]
 fn convert_device_off_reason() {
     assert_eq!(convert_off_reason("0x00000000".to_string()).unwrap(), [OffReason::None]);
@@ -100,12 +137,16 @@ fn convert_test() {
     let mut map = HashMap::<String, Vec<u8>>::new();
     map.insert("V".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("VS".to_string(), "12488.12".as_bytes().to_vec());
+    map.insert("V2".to_string(), "12488.12".as_bytes().to_vec());
+    map.insert("V3".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("VM".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("DM".to_string(), "0.012".as_bytes().to_vec());
     map.insert("VPV".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("PPV".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("I".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("IL".to_string(), "12488.12".as_bytes().to_vec());
+    map.insert("I2".to_string(), "12488.12".as_bytes().to_vec());
+    map.insert("I3".to_string(), "12488.12".as_bytes().to_vec());
     map.insert("LOAD".to_string(), "ON".as_bytes().to_vec());
     map.insert("T".to_string(), "43.233".as_bytes().to_vec());
     map.insert("P".to_string(), "12488.12".as_bytes().to_vec());
@@ -147,9 +188,14 @@ fn convert_test() {
     map.insert("SER#".to_string(), "STRING".as_bytes().to_vec());
     map.insert("HSDS".to_string(), "42".as_bytes().to_vec());
     map.insert("MODE".to_string(), "5".as_bytes().to_vec());
+    map.insert("MPPT".to_string(), "2".as_bytes().to_vec());
+    map.insert("MON".to_string(), "-3".as_bytes().to_vec());
     map.insert("AC_OUT_V".to_string(), "12488".as_bytes().to_vec());
     map.insert("AC_OUT_I".to_string(), "12488".as_bytes().to_vec());
     map.insert("AC_OUT_S".to_string(), "12488".as_bytes().to_vec());
+    map.insert("DC_IN_V".to_string(), "1350".as_bytes().to_vec());
+    map.insert("DC_IN_I".to_string(), "105".as_bytes().to_vec());
+    map.insert("DC_IN_P".to_string(), "141".as_bytes().to_vec());
     map.insert("WARN".to_string(), "32".as_bytes().to_vec());
     map.insert("Calc_sum".to_string(), "a".as_bytes().to_vec());
     map.insert("Checksum".to_string(), "u".as_bytes().to_vec());
@@ -159,21 +205,26 @@ fn convert_test() {
     map.insert("Bleble".to_string(), "ola".as_bytes().to_vec());
 
     let conv = convert(map).unwrap();
+    assert_eq!(convert(revert(&conv)).unwrap(), conv);
 
     assert_eq!(conv.V.unwrap(), 12.48812);
     assert_eq!(conv.VS.unwrap(), 12.48812);
+    assert_eq!(conv.V2.unwrap(), 12.48812);
+    assert_eq!(conv.V3.unwrap(), 12.48812);
     assert_eq!(conv.VM.unwrap(), 12.48812);
-    assert_eq!(conv.DM.unwrap(), 0.012);
+    assert_eq!(conv.DM.unwrap(), 0.012 / 1000_f64);
     assert_eq!(conv.VPV.unwrap(), 12.48812);
     assert_eq!(conv.PPV.unwrap(), 12488.12);
     assert_eq!(conv.I.unwrap(), 12.48812);
     assert_eq!(conv.IL.unwrap(), 12.48812);
+    assert_eq!(conv.I2.unwrap(), 12.48812);
+    assert_eq!(conv.I3.unwrap(), 12.48812);
     assert_eq!(conv.LOAD.unwrap(), Load::On);
     assert_eq!(conv.T.unwrap(), 43.233);
     assert_eq!(conv.P.unwrap(), 12488.12);
     assert_eq!(conv.CE.unwrap(), 12.48812);
-    assert_eq!(conv.SOC.unwrap(), 23.12);
-    assert_eq!(conv.TTG.unwrap(), 23.12 * 60_f64);
+    assert_eq!(conv.SOC.unwrap(), 23.12 / 1000_f64);
+    assert_eq!(conv.TTG.unwrap(), TimeToGo::Remaining(Duration::from_secs_f64(23.12 * 60_f64)));
     assert_eq!(conv.Alarm.unwrap(), Alarm::On);
     assert_eq!(conv.Relay.unwrap(), Relay::Off);
     assert_eq!(conv.AR.unwrap(), vec![AlarmReason::LowStarterVoltage]);
@@ -186,7 +237,7 @@ fn convert_test() {
     assert_eq!(conv.H6.unwrap(), 12.48812);
     assert_eq!(conv.H7.unwrap(), 12.48812);
     assert_eq!(conv.H8.unwrap(), 12.48812);
-    assert_eq!(conv.H9.unwrap(), 1233.0);
+    assert_eq!(conv.H9.unwrap(), Duration::from_secs(1233));
     assert_eq!(conv.H10.unwrap(), 554_f64);
     assert_eq!(conv.H11.unwrap(), 554_f64);
     assert_eq!(conv.H12.unwrap(), 554_f64);
@@ -208,14 +259,199 @@ fn convert_test() {
     assert_eq!(conv.SER.unwrap(), "STRING".to_string());
     assert_eq!(conv.HSDS.unwrap(), 42_f64);
     assert_eq!(conv.MODE.unwrap(), DeviceMode::VE_REG_MODE_ECO);
+    assert_eq!(conv.MPPT.unwrap(), TrackerMode::MppTrackerActive);
+    assert_eq!(conv.MON.unwrap(), MonitorMode::DcDcCharger);
     assert_eq!(conv.AC_OUT_V.unwrap(), 124.88);
     assert_eq!(conv.AC_OUT_I.unwrap(), 1248.8);
     assert_eq!(conv.AC_OUT_S.unwrap(), 12488.0);
+    assert_eq!(conv.DC_IN_V.unwrap(), 13.5);
+    assert_eq!(conv.DC_IN_I.unwrap(), 10.5);
+    assert_eq!(conv.DC_IN_P.unwrap(), 141.0);
     assert_eq!(conv.WARN.unwrap(), vec![WarningReason::LowTemperature]);
     assert_eq!(conv.Calc_sum.unwrap(), 97);
     assert_eq!(conv.Checksum.unwrap(), 117);
     assert_eq!(conv.BLE.unwrap(), BluetoothStatus::Off);
     assert_eq!(conv.CAP_BLE.unwrap(), BluetoothCapBle::BLE_Switching_Off_Is_Permanent);
     assert_eq!(conv.Time.unwrap(), 12344556789);
+    let unknown = conv.Unknown.unwrap();
+    assert_eq!(unknown["Bleble"].raw, b"ola".to_vec());
+    assert_eq!(unknown["Bleble"].lossy, "ola");
+}
+
+#[test]
+fn convert_v1_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12488".as_bytes().to_vec());
+    map.insert("DM".to_string(), "0.012".as_bytes().to_vec());
+    map.insert("SOC".to_string(), "23.12".as_bytes().to_vec());
+    map.insert("TTG".to_string(), "23.12".as_bytes().to_vec());
+    map.insert("H9".to_string(), "1233".as_bytes().to_vec());
+    map.insert("Bleble".to_string(), "ola".as_bytes().to_vec());
+
+    let conv = convert_v1(map).unwrap();
+
+    assert_eq!(conv.V.unwrap(), 12.488);
+    assert_eq!(conv.DM.unwrap(), 0.012);
+    assert_eq!(conv.SOC.unwrap(), 23.12);
+    assert_eq!(conv.TTG.unwrap(), 23.12 * 60_f64);
+    assert_eq!(conv.H9.unwrap(), 1233.0);
     assert_eq!(conv.Unknown.unwrap(), vec!["Bleble: ola"]);
 }
+
+#[test]
+fn convert_v1_labels_since_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12488".as_bytes().to_vec());
+    map.insert("MPPT".to_string(), "2".as_bytes().to_vec());
+    map.insert("MON".to_string(), "-1".as_bytes().to_vec());
+
+    let conv = convert_v1(map).unwrap();
+
+    assert_eq!(conv.V.unwrap(), 12.488);
+    assert_eq!(conv.Unknown.unwrap(), vec!["MPPT: 2", "MON: -1"]);
+}
+
+#[cfg(feature = "uom")]
+#[test]
+fn quantities_test() {
+    use super::quantities::VEDirectQuantities;
+    use uom::si::electric_charge::ampere_hour;
+    use uom::si::electric_potential::millivolt;
+    use uom::si::energy::kilowatt_hour;
+    use uom::si::ratio::percent;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::time::{minute, second};
+
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12540".as_bytes().to_vec());
+    map.insert("CE".to_string(), "-1500".as_bytes().to_vec());
+    map.insert("SOC".to_string(), "876".as_bytes().to_vec());
+    map.insert("TTG".to_string(), "90".as_bytes().to_vec());
+    map.insert("T".to_string(), "25".as_bytes().to_vec());
+    map.insert("H9".to_string(), "120".as_bytes().to_vec());
+    map.insert("H19".to_string(), "144".as_bytes().to_vec());
+
+    let quantities = VEDirectQuantities::from(&convert(map).unwrap());
+    assert!((quantities.V.unwrap().get::<millivolt>() - 12540.0).abs() < 1e-9);
+    assert!((quantities.CE.unwrap().get::<ampere_hour>() + 1.5).abs() < 1e-9);
+    assert!((quantities.SOC.unwrap().get::<percent>() - 87.6).abs() < 1e-9);
+    assert!((quantities.TTG.unwrap().get::<minute>() - 90.0).abs() < 1e-9);
+    assert!((quantities.T.unwrap().get::<degree_celsius>() - 25.0).abs() < 1e-9);
+    assert!((quantities.H9.unwrap().get::<second>() - 120.0).abs() < 1e-9);
+    assert!((quantities.H19.unwrap().get::<kilowatt_hour>() - 1.44).abs() < 1e-9);
+    assert_eq!(quantities.VPV, None);
+}
+
+#[test]
+fn convert_exact_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12540".as_bytes().to_vec());
+    map.insert("I".to_string(), "-40".as_bytes().to_vec());
+    map.insert("VS".to_string(), "12488.12".as_bytes().to_vec());
+    map.insert("SOC".to_string(), "876".as_bytes().to_vec());
+    map.insert("TTG".to_string(), "-1".as_bytes().to_vec());
+    map.insert("H19".to_string(), "144".as_bytes().to_vec());
+    map.insert("AC_OUT_V".to_string(), "23002".as_bytes().to_vec());
+    map.insert("CS".to_string(), "3".as_bytes().to_vec());
+
+    let exact = convert_exact(&map);
+    assert_eq!(exact.V.unwrap(), 12540);
+    assert_eq!(exact.I.unwrap(), -40);
+    assert_eq!(exact.VS, None);
+    assert_eq!(exact.SOC.unwrap(), 876);
+    assert_eq!(exact.TTG.unwrap(), -1);
+    assert_eq!(exact.H19.unwrap(), 144);
+    assert_eq!(exact.AC_OUT_V.unwrap(), 23002);
+    assert_eq!(exact.VPV, None);
+
+    // parse is left intact for regular conversion
+    assert_eq!(convert(map).unwrap().V.unwrap(), 12.54);
+}
+
+#[test]
+fn registry_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12488".as_bytes().to_vec());
+    map.insert("PPV".to_string(), "120".as_bytes().to_vec());
+    map.insert("TBAT".to_string(), "2312".as_bytes().to_vec());
+    map.insert("Bleble".to_string(), "ola".as_bytes().to_vec());
+
+    let mut registry = ConverterRegistry::new();
+    registry
+        .register("TBAT", |value: String| convert_cv(value))
+        .register("PPV", |value: String| Ok(format!("{} W", value)));
+    assert!(registry.contains("TBAT"));
+
+    let conv = registry.convert(map).unwrap();
+
+    assert_eq!(conv.V.unwrap(), 12.488);
+    assert_eq!(conv.PPV, None);
+    assert_eq!(conv.extension::<f64>("TBAT").unwrap(), 23.12);
+    assert_eq!(conv.extension::<String>("PPV").unwrap(), "120 W");
+    assert_eq!(conv.extension::<u8>("PPV"), None);
+    assert_eq!(conv.extension::<f64>("Bleble"), None);
+    assert!(conv.Unknown.unwrap().contains_key("Bleble"));
+
+    assert!(registry.unregister("PPV"));
+    assert!(!registry.unregister("PPV"));
+}
+
+#[test]
+fn registry_failing_converter_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12488".as_bytes().to_vec());
+    map.insert("TBAT".to_string(), "warm".as_bytes().to_vec());
+
+    let mut registry = ConverterRegistry::new();
+    registry.register("TBAT", |value: String| convert_cv(value));
+    let conv = registry.convert(map).unwrap();
+
+    assert_eq!(conv.V.unwrap(), 12.488);
+    assert_eq!(conv.Extensions, None);
+    assert_eq!(conv.extension::<f64>("TBAT"), None);
+    let unknown = conv.Unknown.unwrap();
+    assert_eq!(unknown["TBAT"].raw, b"warm".to_vec());
+    assert_eq!(unknown["TBAT"].lossy, "warm");
+}
+
+#[test]
+fn unknown_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("Zeta".to_string(), "1".as_bytes().to_vec());
+    map.insert("Alpha".to_string(), vec![0x6f, 0xff, 0x6b]);
+
+    let conv = convert(map).unwrap();
+    let unknown = conv.Unknown.as_ref().unwrap();
+
+    assert_eq!(unknown.keys().collect::<Vec<_>>(), vec!["Alpha", "Zeta"]);
+    assert_eq!(unknown["Alpha"].raw, vec![0x6f, 0xff, 0x6b]);
+    assert_eq!(unknown["Alpha"].lossy, "o\u{fffd}k");
+
+    let json = serde_json::to_value(&conv).unwrap();
+    assert_eq!(json["Unknown"]["Zeta"], serde_json::json!({"raw": [49], "lossy": "1"}));
+}
+
+#[test]
+fn revert_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("V".to_string(), "12540".as_bytes().to_vec());
+    map.insert("DM".to_string(), "-12".as_bytes().to_vec());
+    map.insert("AC_OUT_V".to_string(), "23012".as_bytes().to_vec());
+    map.insert("H20".to_string(), "144".as_bytes().to_vec());
+    map.insert("TTG".to_string(), "95".as_bytes().to_vec());
+    map.insert("H9".to_string(), "1233".as_bytes().to_vec());
+    map.insert("LOAD".to_string(), "ON".as_bytes().to_vec());
+    map.insert("AR".to_string(), "10".as_bytes().to_vec());
+    map.insert("OR".to_string(), "0x00000005".as_bytes().to_vec());
+    map.insert("MON".to_string(), "-3".as_bytes().to_vec());
+    map.insert("Checksum".to_string(), vec![0xd8]);
+    map.insert("Bleble".to_string(), vec![0x6f, 0xff]);
+
+    let reverted = revert(&convert(map.clone()).unwrap());
+
+    assert_eq!(reverted, map);
+    assert_eq!(revert_ma(&0.3), "300");
+    assert_eq!(revert_time_to_go(&TimeToGo::Infinite), "-1");
+    assert_eq!(revert_off_reason(&[OffReason::None]), "0x00000000");
+    assert_eq!(revert_alarm_reason(&[AlarmReason::None]), "0");
+}
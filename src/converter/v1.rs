@@ -0,0 +1,175 @@
+//! Output of converter before 0.2, kept so existing users can migrate field by field.
+//! Differs from current VEDirectData in:
+//! - `SOC` and `DM` are in permille instead of fraction of unity
+//! - `TTG` is in seconds with infinite reported as -60 instead of TimeToGo
+//! - `H9` is in seconds instead of Duration
+//! - labels added since (`MPPT`, `MON`, `V2`, `V3`, `I2`, `I3`, `DC_IN_V`, `DC_IN_I`, `DC_IN_P`) are kept in `Unknown`
+
+use crate::converter::convert;
+use crate::converter::models::*;
+use crate::parser::VEDirectParse;
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct VEDirectDataV1 {
+    pub V: Option<f64>,
+    pub VS: Option<f64>,
+    pub VM: Option<f64>,
+    pub DM: Option<f64>,
+    pub VPV: Option<f64>,
+    pub PPV: Option<f64>,
+    pub I: Option<f64>,
+    pub IL: Option<f64>,
+    pub LOAD: Option<Load>,
+    pub T: Option<f64>,
+    pub P: Option<f64>,
+    pub CE: Option<f64>,
+    pub SOC: Option<f64>,
+    pub TTG: Option<f64>,
+    pub Alarm: Option<Alarm>,
+    pub Relay: Option<Relay>,
+    pub AR: Option<Vec<AlarmReason>>,
+    pub OR: Option<Vec<OffReason>>,
+    pub H1: Option<f64>,
+    pub H2: Option<f64>,
+    pub H3: Option<f64>,
+    pub H4: Option<f64>,
+    pub H5: Option<f64>,
+    pub H6: Option<f64>,
+    pub H7: Option<f64>,
+    pub H8: Option<f64>,
+    pub H9: Option<f64>,
+    pub H10: Option<f64>,
+    pub H11: Option<f64>,
+    pub H12: Option<f64>,
+    pub H13: Option<f64>,
+    pub H14: Option<f64>,
+    pub H15: Option<f64>,
+    pub H16: Option<f64>,
+    pub H17: Option<f64>,
+    pub H18: Option<f64>,
+    pub H19: Option<f64>,
+    pub H20: Option<f64>,
+    pub H21: Option<f64>,
+    pub H22: Option<f64>,
+    pub H23: Option<f64>,
+    pub ERR: Option<ErrorCode>,
+    pub CS: Option<StateOfOperation>,
+    pub BMV: Option<String>,
+    pub FW: Option<String>,
+    pub FWE: Option<String>,
+    pub PID: Option<String>,
+    pub SER: Option<String>,
+    pub HSDS: Option<f64>,
+    pub MODE: Option<DeviceMode>,
+    pub AC_OUT_V: Option<f64>,
+    pub AC_OUT_I: Option<f64>,
+    pub AC_OUT_S: Option<f64>,
+    pub WARN: Option<Vec<WarningReason>>,
+    pub Calc_sum: Option<u8>,
+    pub Checksum: Option<u8>,
+    pub BLE: Option<BluetoothStatus>,
+    pub CAP_BLE: Option<BluetoothCapBle>,
+    pub Time: Option<i64>,
+    pub Unknown: Option<Vec<String>>,
+}
+
+/// labels converter did not know before 0.2
+const LABELS_SINCE_V1: [Labels; 9] = [
+    Labels::MPPT,
+    Labels::MON,
+    Labels::V2,
+    Labels::V3,
+    Labels::I2,
+    Labels::I3,
+    Labels::DC_IN_V,
+    Labels::DC_IN_I,
+    Labels::DC_IN_P,
+];
+
+/// converts parse the way converter did before 0.2
+#[allow(non_snake_case)]
+pub fn convert_v1(mut map: VEDirectParse) -> Result<VEDirectDataV1> {
+    let DM = crate::convert!(map, Labels::DM, convert_parse);
+    let SOC = crate::convert!(map, Labels::SOC, convert_parse);
+    let TTG = crate::convert!(map, Labels::TTG, convert_minutes);
+    let H9 = crate::convert!(map, Labels::H9, convert_parse);
+    let mut unknown = Vec::new();
+    for label in LABELS_SINCE_V1 {
+        if let Some(value) = map.remove(&label.to_string()) {
+            unknown.push(format!("{}: {}", label, String::from_utf8_lossy(value.as_slice())));
+        }
+    }
+    let data = convert(map)?;
+    unknown.extend(
+        data.Unknown
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| format!("{}: {}", key, value.lossy)),
+    );
+
+    Ok(VEDirectDataV1 {
+        V: data.V,
+        VS: data.VS,
+        VM: data.VM,
+        DM,
+        VPV: data.VPV,
+        PPV: data.PPV,
+        I: data.I,
+        IL: data.IL,
+        LOAD: data.LOAD,
+        T: data.T,
+        P: data.P,
+        CE: data.CE,
+        SOC,
+        TTG,
+        Alarm: data.Alarm,
+        Relay: data.Relay,
+        AR: data.AR,
+        OR: data.OR,
+        H1: data.H1,
+        H2: data.H2,
+        H3: data.H3,
+        H4: data.H4,
+        H5: data.H5,
+        H6: data.H6,
+        H7: data.H7,
+        H8: data.H8,
+        H9,
+        H10: data.H10,
+        H11: data.H11,
+        H12: data.H12,
+        H13: data.H13,
+        H14: data.H14,
+        H15: data.H15,
+        H16: data.H16,
+        H17: data.H17,
+        H18: data.H18,
+        H19: data.H19,
+        H20: data.H20,
+        H21: data.H21,
+        H22: data.H22,
+        H23: data.H23,
+        ERR: data.ERR,
+        CS: data.CS,
+        BMV: data.BMV,
+        FW: data.FW,
+        FWE: data.FWE,
+        PID: data.PID,
+        SER: data.SER,
+        HSDS: data.HSDS,
+        MODE: data.MODE,
+        AC_OUT_V: data.AC_OUT_V,
+        AC_OUT_I: data.AC_OUT_I,
+        AC_OUT_S: data.AC_OUT_S,
+        WARN: data.WARN,
+        Calc_sum: data.Calc_sum,
+        Checksum: data.Checksum,
+        BLE: data.BLE,
+        CAP_BLE: data.CAP_BLE,
+        Time: data.Time,
+        Unknown: if !unknown.is_empty() { Some(unknown) } else { None },
+    })
+}
@@ -0,0 +1,77 @@
+//! # Example
+//! ```
+//! // converted variable from converter output
+//! let metrics = derived_metrics(&converted);
+//! if let (Some(battery), Some(load)) = (metrics.battery_power, metrics.load_power) {
+//!     println!("battery: {} W, load: {} W", battery, load);
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Ratio, VEDirectData, A, W};
+
+/// power flowing into battery, negative when discharging
+pub fn battery_power(data: &VEDirectData) -> Option<W> {
+    Some(data.V? * data.I?)
+}
+
+/// net battery current summed over outputs (I, I2, I3) of chargers with several of them, negative when discharging;
+/// `I` itself is already net of load output (IL)
+pub fn net_current(data: &VEDirectData) -> Option<A> {
+    Some(data.I? + data.I2.unwrap_or(0_f64) + data.I3.unwrap_or(0_f64))
+}
+
+/// panel current, panel disconnected at night reports 0 V so current is 0 as well
+pub fn pv_current(data: &VEDirectData) -> Option<A> {
+    let ppv = data.PPV?;
+    let vpv = data.VPV?;
+    if vpv > 0_f64 {
+        Some(ppv / vpv)
+    } else {
+        Some(0_f64)
+    }
+}
+
+/// power drawn from load output of solar charger
+pub fn load_power(data: &VEDirectData) -> Option<W> {
+    Some(data.V? * data.IL?)
+}
+
+/// power delivered by charger to battery and load output together
+pub fn charger_power(data: &VEDirectData) -> Option<W> {
+    Some(data.V? * (data.I? + data.IL?))
+}
+
+/// part of charger power consumed by load output
+pub fn load_share(data: &VEDirectData) -> Option<Ratio> {
+    let charger = charger_power(data)?;
+    if charger > 0_f64 {
+        Some((load_power(data)? / charger).clamp(0_f64, 1_f64))
+    } else {
+        None
+    }
+}
+
+/// apparent power of inverter output computed from voltage and current
+pub fn ac_apparent_power(data: &VEDirectData) -> Option<f64> {
+    Some(data.AC_OUT_V? * data.AC_OUT_I?)
+}
+
+/// computes all derived metrics of packet
+pub fn derived_metrics(data: &VEDirectData) -> DerivedMetrics {
+    DerivedMetrics {
+        battery_power: battery_power(data),
+        net_current: net_current(data),
+        pv_current: pv_current(data),
+        load_power: load_power(data),
+        charger_power: charger_power(data),
+        load_share: load_share(data),
+        ac_apparent_power: ac_apparent_power(data),
+        ac_reported_power: data.AC_OUT_S,
+    }
+}
@@ -0,0 +1,16 @@
+use crate::converter::models::{Ratio, A, W};
+use serde::{Deserialize, Serialize};
+
+/// Metrics computed from fields reported by device, each one is `None` when any of its inputs is missing.
+/// Inverters report apparent power only (`AC_OUT_S`), real power and power factor are not available over VE.Direct.
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DerivedMetrics {
+    pub battery_power: Option<W>,       // [W] V × I, positive when charging
+    pub net_current: Option<A>,         // [A] I + I2 + I3, positive when charging
+    pub pv_current: Option<A>,          // [A] PPV / VPV, 0 when panel voltage is 0
+    pub load_power: Option<W>,          // [W] V × IL
+    pub charger_power: Option<W>,       // [W] V × (I + IL), charger output split into battery and load
+    pub load_share: Option<Ratio>,      // [0..1] load_power / charger_power, `None` when charger is not producing
+    pub ac_apparent_power: Option<f64>, // [VA] AC_OUT_V × AC_OUT_I
+    pub ac_reported_power: Option<f64>, // [VA] AC_OUT_S as reported by inverter
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_utils::{data_from, round};
+
+#[test]
+fn solar_charger_test() {
+    let data = data_from(&[("V", "12540"), ("I", "4000"), ("IL", "1000"), ("VPV", "18540"), ("PPV", "65")]);
+    let metrics = derived_metrics(&data);
+
+    assert_eq!(round(metrics.battery_power.unwrap()), 50.16);
+    assert_eq!(metrics.net_current.unwrap(), 4.0);
+    assert_eq!(round(metrics.load_power.unwrap()), 12.54);
+    assert_eq!(round(metrics.charger_power.unwrap()), 62.7);
+    assert_eq!(round(metrics.load_share.unwrap()), 0.2);
+    assert_eq!(round(metrics.pv_current.unwrap()), 3.506);
+    assert_eq!(metrics.ac_apparent_power, None);
+    assert_eq!(metrics.ac_reported_power, None);
+}
+
+#[test]
+fn missing_inputs_test() {
+    let data = data_from(&[("V", "12540"), ("PPV", "0"), ("VPV", "0")]);
+    let metrics = derived_metrics(&data);
+
+    assert_eq!(metrics.battery_power, None);
+    assert_eq!(metrics.net_current, None);
+    assert_eq!(metrics.load_power, None);
+    assert_eq!(metrics.charger_power, None);
+    assert_eq!(metrics.load_share, None);
+    assert_eq!(metrics.pv_current.unwrap(), 0.0);
+
+    let data = data_from(&[("V", "12540"), ("I", "-2000"), ("IL", "1000")]);
+    assert_eq!(load_share(&data), None);
+    assert_eq!(round(battery_power(&data).unwrap()), -25.08);
+}
+
+#[test]
+fn multiple_outputs_test() {
+    let data = data_from(&[
+        ("V", "13200"),
+        ("I", "5000"),
+        ("V2", "13100"),
+        ("I2", "3000"),
+        ("V3", "13100"),
+        ("I3", "500"),
+    ]);
+    assert_eq!(round(net_current(&data).unwrap()), 8.5);
+}
+
+#[test]
+fn inverter_test() {
+    let data = data_from(&[("V", "12540"), ("AC_OUT_V", "23000"), ("AC_OUT_I", "12"), ("AC_OUT_S", "280")]);
+    let metrics = derived_metrics(&data);
+
+    assert_eq!(round(metrics.ac_apparent_power.unwrap()), 276.0);
+    assert_eq!(metrics.ac_reported_power.unwrap(), 280.0);
+}
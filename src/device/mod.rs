@@ -0,0 +1,205 @@
+//! # Example
+//! ```
+//! // converted variable from converter output
+//! match classify(converted) {
+//!     DeviceData::SolarCharger(mppt) => println!("panel power {} W", mppt.PPV),
+//!     DeviceData::BatteryMonitor(bmv) => println!("state of charge {}", bmv.SOC),
+//!     other => println!("{:?}", other),
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::VEDirectData;
+use crate::product::models::ProductFamily;
+use crate::product::product_info;
+use std::io::{Error, ErrorKind, Result};
+
+macro_rules! required {
+    ( $data: ident, $field: ident) => {{
+        $data
+            .$field
+            .clone()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("missing field {}", stringify!($field))))?
+    }};
+}
+
+/// determines product family from PID, falls back to labels observed in the packet
+pub fn detect_family(data: &VEDirectData) -> Option<ProductFamily> {
+    if let Some(product) = data.PID.as_deref().and_then(product_info) {
+        return Some(product.family);
+    }
+    if data.VPV.is_some() || data.PPV.is_some() {
+        Some(ProductFamily::SolarCharger)
+    } else if data.SOC.is_some() || data.CE.is_some() {
+        Some(ProductFamily::BatteryMonitor)
+    } else if data.AC_OUT_V.is_some() {
+        Some(ProductFamily::Inverter)
+    } else {
+        None
+    }
+}
+
+/// converts generic record into family specific one, falls back to DeviceData::Generic.
+/// Frames deliberately left Generic:
+/// - AC chargers, which have no record of their own, their labels vary too much between models
+/// - history blocks of battery monitors (H1..H18 only), which are sent apart from the main block
+///   and lack the fields of the family; copy them into the main block first to get `BatteryMonitorData`
+pub fn classify(data: VEDirectData) -> DeviceData {
+    let typed = match detect_family(&data) {
+        Some(ProductFamily::SolarCharger) => SolarChargerData::try_from(&data).map(DeviceData::SolarCharger),
+        Some(ProductFamily::BatteryMonitor) => BatteryMonitorData::try_from(&data).map(DeviceData::BatteryMonitor),
+        Some(ProductFamily::Inverter) => InverterData::try_from(&data).map(DeviceData::Inverter),
+        Some(ProductFamily::DcDcConverter) => DcDcConverterData::try_from(&data).map(DeviceData::DcDcConverter),
+        Some(ProductFamily::BatteryProtect) => BatteryProtectData::try_from(&data).map(DeviceData::BatteryProtect),
+        Some(ProductFamily::AcCharger) | None => Err(Error::from(ErrorKind::Unsupported)),
+    };
+    typed.unwrap_or(DeviceData::Generic(data))
+}
+
+impl TryFrom<&VEDirectData> for SolarChargerData {
+    type Error = Error;
+
+    fn try_from(data: &VEDirectData) -> Result<Self> {
+        Ok(SolarChargerData {
+            PID: required!(data, PID),
+            V: required!(data, V),
+            I: required!(data, I),
+            VPV: required!(data, VPV),
+            PPV: required!(data, PPV),
+            CS: required!(data, CS),
+            ERR: required!(data, ERR),
+            H19: required!(data, H19),
+            H20: required!(data, H20),
+            H21: required!(data, H21),
+            H22: required!(data, H22),
+            H23: required!(data, H23),
+            HSDS: required!(data, HSDS),
+            MPPT: data.MPPT.clone(),
+            OR: data.OR.clone(),
+            LOAD: data.LOAD.clone(),
+            IL: data.IL,
+            FW: data.FW.clone(),
+            SER: data.SER.clone(),
+            Calc_sum: data.Calc_sum,
+            Time: data.Time,
+        })
+    }
+}
+
+impl TryFrom<&VEDirectData> for BatteryMonitorData {
+    type Error = Error;
+
+    fn try_from(data: &VEDirectData) -> Result<Self> {
+        Ok(BatteryMonitorData {
+            PID: required!(data, PID),
+            V: required!(data, V),
+            I: required!(data, I),
+            P: required!(data, P),
+            CE: required!(data, CE),
+            SOC: required!(data, SOC),
+            TTG: required!(data, TTG),
+            AR: required!(data, AR),
+            VS: data.VS,
+            VM: data.VM,
+            DM: data.DM,
+            T: data.T,
+            Alarm: data.Alarm.clone(),
+            Relay: data.Relay.clone(),
+            MON: data.MON.clone(),
+            H1: data.H1,
+            H2: data.H2,
+            H3: data.H3,
+            H4: data.H4,
+            H5: data.H5,
+            H6: data.H6,
+            H7: data.H7,
+            H8: data.H8,
+            H9: data.H9,
+            H10: data.H10,
+            H11: data.H11,
+            H12: data.H12,
+            H13: data.H13,
+            H14: data.H14,
+            H15: data.H15,
+            H16: data.H16,
+            H17: data.H17,
+            H18: data.H18,
+            BMV: data.BMV.clone(),
+            FW: data.FW.clone(),
+            SER: data.SER.clone(),
+            Calc_sum: data.Calc_sum,
+            Time: data.Time,
+        })
+    }
+}
+
+impl TryFrom<&VEDirectData> for InverterData {
+    type Error = Error;
+
+    fn try_from(data: &VEDirectData) -> Result<Self> {
+        Ok(InverterData {
+            PID: required!(data, PID),
+            V: required!(data, V),
+            MODE: required!(data, MODE),
+            CS: required!(data, CS),
+            AR: required!(data, AR),
+            WARN: required!(data, WARN),
+            AC_OUT_V: required!(data, AC_OUT_V),
+            AC_OUT_I: required!(data, AC_OUT_I),
+            AC_OUT_S: data.AC_OUT_S,
+            OR: data.OR.clone(),
+            FW: data.FW.clone(),
+            SER: data.SER.clone(),
+            Calc_sum: data.Calc_sum,
+            Time: data.Time,
+        })
+    }
+}
+
+impl TryFrom<&VEDirectData> for DcDcConverterData {
+    type Error = Error;
+
+    fn try_from(data: &VEDirectData) -> Result<Self> {
+        Ok(DcDcConverterData {
+            PID: required!(data, PID),
+            MODE: required!(data, MODE),
+            CS: required!(data, CS),
+            ERR: required!(data, ERR),
+            OR: required!(data, OR),
+            V: data.V,
+            DC_IN_V: data.DC_IN_V,
+            DC_IN_I: data.DC_IN_I,
+            DC_IN_P: data.DC_IN_P,
+            FW: data.FW.clone(),
+            SER: data.SER.clone(),
+            Calc_sum: data.Calc_sum,
+            Time: data.Time,
+        })
+    }
+}
+
+impl TryFrom<&VEDirectData> for BatteryProtectData {
+    type Error = Error;
+
+    fn try_from(data: &VEDirectData) -> Result<Self> {
+        Ok(BatteryProtectData {
+            PID: required!(data, PID),
+            MODE: required!(data, MODE),
+            CS: required!(data, CS),
+            ERR: required!(data, ERR),
+            AR: data.AR.clone(),
+            WARN: data.WARN.clone(),
+            OR: data.OR.clone(),
+            V: data.V,
+            FW: data.FW.clone(),
+            SER: data.SER.clone(),
+            Calc_sum: data.Calc_sum,
+            Time: data.Time,
+        })
+    }
+}
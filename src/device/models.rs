@@ -0,0 +1,143 @@
+use crate::converter::models::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// MPPT solar charger record
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct SolarChargerData {
+    pub PID: String,
+    pub V: f64,
+    pub I: f64,
+    pub VPV: f64,
+    pub PPV: f64,
+    pub CS: StateOfOperation,
+    pub ERR: ErrorCode,
+    pub H19: f64,
+    pub H20: f64,
+    pub H21: f64,
+    pub H22: f64,
+    pub H23: f64,
+    pub HSDS: f64,
+    pub MPPT: Option<TrackerMode>,
+    pub OR: Option<Vec<OffReason>>,
+    pub LOAD: Option<Load>,
+    pub IL: Option<f64>,
+    pub FW: Option<String>,
+    pub SER: Option<String>,
+    pub Calc_sum: Option<u8>,
+    pub Time: Option<i64>,
+}
+
+/// BMV or SmartShunt record
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct BatteryMonitorData {
+    pub PID: String,
+    pub V: f64,
+    pub I: f64,
+    pub P: f64,
+    pub CE: f64,
+    pub SOC: Ratio,
+    pub TTG: TimeToGo,
+    pub AR: Vec<AlarmReason>,
+    pub VS: Option<f64>,
+    pub VM: Option<f64>,
+    pub DM: Option<Ratio>,
+    pub T: Option<DegC>,
+    pub Alarm: Option<Alarm>,
+    pub Relay: Option<Relay>,
+    pub MON: Option<MonitorMode>,
+    pub H1: Option<f64>,
+    pub H2: Option<f64>,
+    pub H3: Option<f64>,
+    pub H4: Option<f64>,
+    pub H5: Option<f64>,
+    pub H6: Option<f64>,
+    pub H7: Option<f64>,
+    pub H8: Option<f64>,
+    pub H9: Option<Duration>,
+    pub H10: Option<f64>,
+    pub H11: Option<f64>,
+    pub H12: Option<f64>,
+    pub H13: Option<f64>,
+    pub H14: Option<f64>,
+    pub H15: Option<f64>,
+    pub H16: Option<f64>,
+    pub H17: Option<f64>,
+    pub H18: Option<f64>,
+    pub BMV: Option<String>,
+    pub FW: Option<String>,
+    pub SER: Option<String>,
+    pub Calc_sum: Option<u8>,
+    pub Time: Option<i64>,
+}
+
+/// Phoenix inverter record
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct InverterData {
+    pub PID: String,
+    pub V: f64,
+    pub MODE: DeviceMode,
+    pub CS: StateOfOperation,
+    pub AR: Vec<AlarmReason>,
+    pub WARN: Vec<WarningReason>,
+    pub AC_OUT_V: f64,
+    pub AC_OUT_I: f64,
+    pub AC_OUT_S: Option<f64>,
+    pub OR: Option<Vec<OffReason>>,
+    pub FW: Option<String>,
+    pub SER: Option<String>,
+    pub Calc_sum: Option<u8>,
+    pub Time: Option<i64>,
+}
+
+/// Orion dc-dc converter record
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct DcDcConverterData {
+    pub PID: String,
+    pub MODE: DeviceMode,
+    pub CS: StateOfOperation,
+    pub ERR: ErrorCode,
+    pub OR: Vec<OffReason>,
+    pub V: Option<f64>,
+    pub DC_IN_V: Option<f64>,
+    pub DC_IN_I: Option<f64>,
+    pub DC_IN_P: Option<f64>,
+    pub FW: Option<String>,
+    pub SER: Option<String>,
+    pub Calc_sum: Option<u8>,
+    pub Time: Option<i64>,
+}
+
+/// Smart BatteryProtect record
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct BatteryProtectData {
+    pub PID: String,
+    pub MODE: DeviceMode,
+    pub CS: StateOfOperation,
+    pub ERR: ErrorCode,
+    pub AR: Option<Vec<AlarmReason>>,
+    pub WARN: Option<Vec<WarningReason>>,
+    pub OR: Option<Vec<OffReason>>,
+    pub V: Option<f64>,
+    pub FW: Option<String>,
+    pub SER: Option<String>,
+    pub Calc_sum: Option<u8>,
+    pub Time: Option<i64>,
+}
+
+/// Record typed by product family, Generic when family is unknown or packet lacks fields the family always sends
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum DeviceData {
+    SolarCharger(SolarChargerData),
+    BatteryMonitor(BatteryMonitorData),
+    Inverter(InverterData),
+    DcDcConverter(DcDcConverterData),
+    BatteryProtect(BatteryProtectData),
+    Generic(VEDirectData),
+}
@@ -0,0 +1,139 @@
+use super::*;
+use crate::converter::models::*;
+use crate::test_utils::data_from;
+
+const MPPT: &[(&str, &str)] = &[
+    ("PID", "0xA053"),
+    ("FW", "159"),
+    ("SER#", "HQ2132QY2KR"),
+    ("V", "12540"),
+    ("I", "40"),
+    ("VPV", "18540"),
+    ("PPV", "5"),
+    ("CS", "3"),
+    ("ERR", "0"),
+    ("LOAD", "ON"),
+    ("IL", "300"),
+    ("H19", "144"),
+    ("H20", "1"),
+    ("H21", "6"),
+    ("H22", "4"),
+    ("H23", "14"),
+    ("HSDS", "16"),
+];
+
+#[test]
+fn detect_family_test() {
+    assert_eq!(detect_family(&data_from(MPPT)), Some(ProductFamily::SolarCharger));
+    assert_eq!(detect_family(&data_from(&[("PID", "0xA254"), ("V", "48000")])), Some(ProductFamily::Inverter));
+    assert_eq!(detect_family(&data_from(&[("VPV", "18540")])), Some(ProductFamily::SolarCharger));
+    assert_eq!(detect_family(&data_from(&[("PID", "0xFFFF"), ("SOC", "1000")])), Some(ProductFamily::BatteryMonitor));
+    assert_eq!(detect_family(&data_from(&[("AC_OUT_V", "23000")])), Some(ProductFamily::Inverter));
+    assert_eq!(detect_family(&data_from(&[("V", "12540")])), None);
+}
+
+#[test]
+fn classify_solar_charger_test() {
+    match classify(data_from(MPPT)) {
+        DeviceData::SolarCharger(mppt) => {
+            assert_eq!(mppt.PID, "0xA053");
+            assert_eq!(mppt.V, 12.54);
+            assert_eq!(mppt.VPV, 18.54);
+            assert_eq!(mppt.CS, StateOfOperation::Bulk);
+            assert_eq!(mppt.H20, 10.0);
+            assert_eq!(mppt.LOAD.unwrap(), Load::On);
+            assert_eq!(mppt.OR, None);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn classify_battery_monitor_test() {
+    let data = data_from(&[
+        ("PID", "0x203"),
+        ("V", "26201"),
+        ("I", "0"),
+        ("P", "0"),
+        ("CE", "0"),
+        ("SOC", "1000"),
+        ("TTG", "-1"),
+        ("Alarm", "OFF"),
+        ("Relay", "OFF"),
+        ("AR", "0"),
+        ("BMV", "700"),
+        ("FW", "0307"),
+    ]);
+    match classify(data) {
+        DeviceData::BatteryMonitor(bmv) => {
+            assert_eq!(bmv.V, 26.201);
+            assert_eq!(bmv.AR, [AlarmReason::None]);
+            assert_eq!(bmv.Relay.unwrap(), Relay::Off);
+            assert_eq!(bmv.BMV.unwrap(), "700");
+            assert_eq!(bmv.H1, None);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn classify_inverter_test() {
+    let data = data_from(&[
+        ("PID", "0xA231"),
+        ("FW", "0112"),
+        ("MODE", "2"),
+        ("CS", "9"),
+        ("AC_OUT_V", "23002"),
+        ("AC_OUT_I", "11"),
+        ("V", "12800"),
+        ("AR", "0"),
+        ("WARN", "0"),
+        ("OR", "0x00000000"),
+    ]);
+    match classify(data) {
+        DeviceData::Inverter(inverter) => {
+            assert_eq!(inverter.MODE, DeviceMode::VE_REG_MODE_INVERTER);
+            assert_eq!(inverter.CS, StateOfOperation::Inverting);
+            assert_eq!(inverter.AC_OUT_V, 230.02);
+            assert_eq!(inverter.AC_OUT_S, None);
+            assert_eq!(inverter.OR.unwrap(), [OffReason::None]);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn classify_dc_dc_and_battery_protect_test() {
+    let fields = [
+        ("PID", "0xA3C0"),
+        ("CS", "3"),
+        ("ERR", "0"),
+        ("OR", "0x00000000"),
+        ("MODE", "2"),
+    ];
+    assert!(matches!(classify(data_from(&fields)), DeviceData::DcDcConverter(_)));
+
+    let fields = [("PID", "0xA3E0"), ("CS", "9"), ("ERR", "0"), ("MODE", "2"), ("AR", "1")];
+    match classify(data_from(&fields)) {
+        DeviceData::BatteryProtect(protect) => assert_eq!(protect.AR.unwrap(), [AlarmReason::LowVoltage]),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn classify_generic_fallback_test() {
+    // missing fields the family always sends
+    let incomplete = data_from(&MPPT[..10]);
+    assert_eq!(classify(incomplete.clone()), DeviceData::Generic(incomplete));
+
+    let unknown = data_from(&[("V", "12540"), ("PID", "0xFFFF")]);
+    assert_eq!(classify(unknown.clone()), DeviceData::Generic(unknown));
+
+    // AC chargers have no record of their own
+    let charger = data_from(&[("PID", "0xA340"), ("V", "12540")]);
+    assert!(matches!(classify(charger), DeviceData::Generic(_)));
+
+    // history block of battery monitor is sent apart from its main block
+    let history = data_from(&[("H1", "-3000"), ("H2", "-1500"), ("H18", "12")]);
+    assert!(matches!(classify(history), DeviceData::Generic(_)));
+}
@@ -0,0 +1,109 @@
+//! # Example
+//! ```
+//! let config = DiffConfig::default()
+//!     .deadband(Labels::V, 0.01)
+//!     .deadband(Labels::PPV, 1.0)
+//!     .snapshot_interval(Duration::from_secs(300));
+//! let mut detector = ChangeDetector::new(config);
+//! // converted variable from converter output
+//! match detector.update(&converted).unwrap() {
+//!     Changes::Snapshot(data) => println!("full: {:?}", data),
+//!     Changes::Delta(changes) => println!("changed: {:?}", changes),
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Labels, VEDirectData};
+use std::io::Result;
+use strum::IntoEnumIterator;
+
+fn within_deadband(config: &DiffConfig, label: Labels, previous: &VEDirectData, current: &VEDirectData) -> bool {
+    match (config.deadbands.get(&label), previous.numeric(label), current.numeric(label)) {
+        (Some(deadband), Some(previous), Some(current)) => (current - previous).abs() <= *deadband,
+        _ => false,
+    }
+}
+
+fn changed(config: &DiffConfig, label: Labels, previous: &VEDirectData, current: &VEDirectData) -> bool {
+    !config.ignored.contains(&label)
+        // fields missing in current record are unchanged, devices send their fields in several blocks
+        && current.has_value(label)
+        && !previous.same_value(current, label)
+        && !within_deadband(config, label, previous, current)
+}
+
+/// per-field changes between two records, fields missing in `current` are not reported
+pub fn diff(previous: &VEDirectData, current: &VEDirectData, config: &DiffConfig) -> Result<Vec<FieldChange>> {
+    Labels::iter()
+        .filter(|label| changed(config, *label, previous, current))
+        .map(|label| {
+            Ok(FieldChange {
+                label,
+                previous: previous.json_value(label)?,
+                current: current.json_value(label)?,
+            })
+        })
+        .collect()
+}
+
+/// Tracks last published state and yields only changes exceeding deadbands,
+/// values within deadband are compared against last published value so slow drift is still reported
+#[derive(PartialEq, Debug, Clone)]
+pub struct ChangeDetector {
+    config: DiffConfig,
+    published: Option<VEDirectData>,
+    last_snapshot: Option<i64>,
+}
+
+impl ChangeDetector {
+    pub fn new(config: DiffConfig) -> Self {
+        Self {
+            config,
+            published: None,
+            last_snapshot: None,
+        }
+    }
+
+    fn snapshot_due(&self, data: &VEDirectData) -> bool {
+        match (self.config.snapshot_interval, self.last_snapshot, data.Time) {
+            (Some(interval), Some(last), Some(time)) => time - last >= interval.as_secs() as i64,
+            (Some(_), None, Some(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// full snapshot for first record and when snapshot interval elapsed, changed fields otherwise
+    pub fn update(&mut self, data: &VEDirectData) -> Result<Changes> {
+        let snapshot_due = self.snapshot_due(data);
+        let published = match self.published.as_mut() {
+            Some(published) if !snapshot_due => published,
+            _ => return Ok(self.snapshot(data)),
+        };
+
+        let changes = diff(published, data, &self.config)?;
+        // published record takes over changed fields only, the rest keeps values last sent
+        for change in changes.iter() {
+            published.copy_value(data, change.label);
+        }
+        Ok(Changes::Delta(changes))
+    }
+
+    /// forces full snapshot on next update
+    pub fn reset(&mut self) {
+        self.published = None;
+        self.last_snapshot = None;
+    }
+
+    fn snapshot(&mut self, data: &VEDirectData) -> Changes {
+        self.published = Some(data.clone());
+        if data.Time.is_some() {
+            self.last_snapshot = data.Time;
+        }
+        Changes::Snapshot(Box::new(data.clone()))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::converter::models::{Labels, VEDirectData};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Change of single field between two records, values are JSON representation of the field
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub label: Labels,
+    pub previous: serde_json::Value, // null when field was missing
+    pub current: serde_json::Value,
+}
+
+/// Output of change detector
+#[derive(PartialEq, Debug, Clone)]
+pub enum Changes {
+    Snapshot(Box<VEDirectData>),
+    Delta(Vec<FieldChange>),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct DiffConfig {
+    pub deadbands: HashMap<Labels, f64>, // numeric changes up to deadband (in normalised unit) are ignored
+    pub ignored: HashSet<Labels>,        // labels never reported as changed
+    pub snapshot_interval: Option<Duration>, // full snapshot when this much time passed since previous one
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        Self {
+            deadbands: HashMap::new(),
+            ignored: HashSet::from([Labels::Time, Labels::Checksum, Labels::Calc_sum]),
+            snapshot_interval: None,
+        }
+    }
+}
+
+impl DiffConfig {
+    pub fn deadband(mut self, label: Labels, deadband: f64) -> Self {
+        self.deadbands.insert(label, deadband);
+        self
+    }
+
+    pub fn ignore(mut self, label: Labels) -> Self {
+        self.ignored.insert(label);
+        self
+    }
+
+    pub fn snapshot_interval(mut self, interval: Duration) -> Self {
+        self.snapshot_interval = Some(interval);
+        self
+    }
+}
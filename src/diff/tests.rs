@@ -0,0 +1,86 @@
+use super::*;
+use crate::test_utils::frame;
+use std::time::Duration;
+
+#[test]
+fn diff_test() {
+    let previous = frame(0, &[("V", "12540"), ("PPV", "120"), ("CS", "3"), ("SER#", "HQ2132QY2KR")]);
+    let current = frame(
+        1,
+        &[
+            ("V", "12545"),
+            ("PPV", "125"),
+            ("CS", "5"),
+            ("LOAD", "ON"),
+            ("SER#", "HQ2132QY2KR"),
+        ],
+    );
+    let config = DiffConfig::default().deadband(Labels::V, 0.01).deadband(Labels::PPV, 1.0);
+
+    let changes = diff(&previous, &current, &config).unwrap();
+
+    assert_eq!(changes.iter().map(|change| change.label).collect::<Vec<_>>(), vec![Labels::PPV, Labels::LOAD, Labels::CS]);
+    assert_eq!(changes[0].previous, serde_json::json!(120.0));
+    assert_eq!(changes[0].current, serde_json::json!(125.0));
+    assert_eq!(changes[1].previous, serde_json::Value::Null);
+    assert_eq!(changes[2].current, serde_json::json!("Float"));
+    assert_eq!(serde_json::to_value(&changes[1]).unwrap()["label"], "LOAD");
+
+    assert!(diff(&current, &current, &DiffConfig::default()).unwrap().is_empty());
+}
+
+#[test]
+fn interleaved_blocks_test() {
+    let main = [("V", "12540"), ("I", "-1000"), ("SOC", "950")];
+    let history = [("H1", "-50000"), ("H4", "12")];
+    assert_eq!(
+        diff(&frame(0, &main), &frame(1, &history), &DiffConfig::default())
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        diff(&frame(1, &history), &frame(2, &main), &DiffConfig::default())
+            .unwrap()
+            .len(),
+        3
+    );
+
+    let mut detector = ChangeDetector::new(DiffConfig::default());
+    assert!(matches!(detector.update(&frame(0, &main)).unwrap(), Changes::Snapshot(_)));
+    assert!(matches!(detector.update(&frame(1, &history)).unwrap(), Changes::Delta(changes) if changes.len() == 2));
+    // fields of the other block are absent, not changed
+    assert_eq!(detector.update(&frame(2, &main)).unwrap(), Changes::Delta(vec![]));
+    assert_eq!(detector.update(&frame(3, &history)).unwrap(), Changes::Delta(vec![]));
+}
+
+#[test]
+fn detector_drift_test() {
+    let mut detector = ChangeDetector::new(DiffConfig::default().deadband(Labels::V, 0.01));
+
+    assert!(matches!(detector.update(&frame(0, &[("V", "12500")])).unwrap(), Changes::Snapshot(_)));
+    assert_eq!(detector.update(&frame(1, &[("V", "12506")])).unwrap(), Changes::Delta(vec![]));
+    // drift accumulated against last published value exceeds deadband
+    match detector.update(&frame(2, &[("V", "12512")])).unwrap() {
+        Changes::Delta(changes) => {
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].previous, serde_json::json!(12.5));
+            assert_eq!(changes[0].current, serde_json::json!(12.512));
+        }
+        Changes::Snapshot(_) => panic!("unexpected snapshot"),
+    }
+    assert_eq!(detector.update(&frame(3, &[("V", "12515")])).unwrap(), Changes::Delta(vec![]));
+}
+
+#[test]
+fn detector_snapshot_test() {
+    let mut detector = ChangeDetector::new(DiffConfig::default().snapshot_interval(Duration::from_secs(10)));
+
+    assert!(matches!(detector.update(&frame(100, &[("V", "12500")])).unwrap(), Changes::Snapshot(_)));
+    assert_eq!(detector.update(&frame(105, &[("V", "12500")])).unwrap(), Changes::Delta(vec![]));
+    assert!(matches!(detector.update(&frame(110, &[("V", "12500")])).unwrap(), Changes::Snapshot(_)));
+    assert_eq!(detector.update(&frame(115, &[("V", "12500")])).unwrap(), Changes::Delta(vec![]));
+
+    detector.reset();
+    assert!(matches!(detector.update(&frame(116, &[("V", "12500")])).unwrap(), Changes::Snapshot(_)));
+}
@@ -0,0 +1,91 @@
+//! # Example
+//! ```
+//! let config = HealthConfig::new(200.0, Chemistry::Agm);
+//! // converted variable from converter output of battery monitor
+//! let report = battery_health(&converted, &config);
+//! println!("{:?} cycles left, {:?}", report.remaining_cycles, report.warnings);
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Ratio, VEDirectData};
+
+/// cycles until end of life when battery is cycled to given depth of discharge
+pub fn cycle_life(profile: &ChemistryProfile, depth_of_discharge: Ratio) -> Option<f64> {
+    if depth_of_discharge > 0_f64 {
+        Some(profile.full_cycles * depth_of_discharge.min(1_f64).powf(-profile.exponent))
+    } else {
+        None
+    }
+}
+
+/// analyses history of battery monitor, fields are `None` when their inputs are missing
+pub fn battery_health(data: &VEDirectData, config: &HealthConfig) -> HealthReport {
+    let profile = config.profile();
+    let relative = |ah: Option<f64>| -> Option<Ratio> {
+        if config.capacity > 0_f64 {
+            ah.map(|ah| ah.abs() / config.capacity)
+        } else {
+            None
+        }
+    };
+
+    let deepest_discharge = relative(data.H1);
+    let average_discharge = relative(data.H3);
+    let equivalent_full_cycles = relative(data.H6);
+    let charge_efficiency = match (data.H17, data.H18) {
+        (Some(discharged), Some(charged)) if charged > 0_f64 => Some(discharged / charged),
+        _ => None,
+    };
+
+    let rated_cycles = average_discharge.and_then(|dod| cycle_life(&profile, dod));
+    // cycles done at average depth that add up to cumulative Ah drawn
+    let cycles_done = match (equivalent_full_cycles, average_discharge) {
+        (Some(efc), Some(dod)) if dod > 0_f64 => Some(efc / dod),
+        _ => None,
+    };
+    let remaining_cycles = rated_cycles.zip(cycles_done).map(|(rated, done)| (rated - done).max(0_f64));
+    let remaining_life = rated_cycles.zip(remaining_cycles).map(|(rated, remaining)| remaining / rated);
+
+    let mut warnings = Vec::new();
+    match data.H5 {
+        Some(full) if full > 0_f64 => warnings.push(HealthWarning::FullDischarges(full as u64)),
+        _ => {}
+    }
+    match deepest_discharge {
+        Some(dod) if dod > profile.recommended_dod => warnings.push(HealthWarning::DeepestDischargeAboveRecommended(dod)),
+        _ => {}
+    }
+    match average_discharge {
+        Some(dod) if dod > profile.recommended_dod => warnings.push(HealthWarning::AverageDischargeAboveRecommended(dod)),
+        _ => {}
+    }
+    match data.H11 {
+        Some(alarms) if alarms > 0_f64 => warnings.push(HealthWarning::LowVoltageAlarms(alarms as u64)),
+        _ => {}
+    }
+    match data.H12 {
+        Some(alarms) if alarms > 0_f64 => warnings.push(HealthWarning::HighVoltageAlarms(alarms as u64)),
+        _ => {}
+    }
+    if remaining_cycles == Some(0_f64) {
+        warnings.push(HealthWarning::EndOfLife);
+    }
+
+    HealthReport {
+        deepest_discharge,
+        last_discharge: relative(data.H2),
+        average_discharge,
+        charge_cycles: data.H4,
+        equivalent_full_cycles,
+        charge_efficiency,
+        rated_cycles,
+        remaining_cycles,
+        remaining_life,
+        warnings,
+    }
+}
@@ -0,0 +1,94 @@
+use crate::converter::models::{Ah, Ratio};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize, EnumIter, Default)]
+pub enum Chemistry {
+    #[default]
+    FloodedLeadAcid,
+    Agm,
+    Gel,
+    LiFePO4,
+}
+
+/// Cycle life model of chemistry, cycles at depth of discharge `d` are `full_cycles * d ^ -exponent`
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ChemistryProfile {
+    pub full_cycles: f64,       // cycles until end of life at 100% depth of discharge
+    pub exponent: f64,          // how steeply cycle life grows with shallower discharges
+    pub recommended_dod: Ratio, // [0..1] depth of discharge not to exceed routinely
+}
+
+impl Chemistry {
+    /// typical datasheet figures, end of life at 80% of rated capacity
+    pub fn profile(&self) -> ChemistryProfile {
+        match self {
+            Chemistry::FloodedLeadAcid => ChemistryProfile {
+                full_cycles: 400.0,
+                exponent: 1.4,
+                recommended_dod: 0.5,
+            },
+            Chemistry::Agm => ChemistryProfile {
+                full_cycles: 300.0,
+                exponent: 1.3,
+                recommended_dod: 0.5,
+            },
+            Chemistry::Gel => ChemistryProfile {
+                full_cycles: 400.0,
+                exponent: 1.35,
+                recommended_dod: 0.5,
+            },
+            Chemistry::LiFePO4 => ChemistryProfile {
+                full_cycles: 3000.0,
+                exponent: 1.0,
+                recommended_dod: 0.8,
+            },
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct HealthConfig {
+    pub capacity: Ah, // [Ah] rated capacity of battery bank, not sent by battery monitor
+    pub chemistry: Chemistry,
+    pub profile: Option<ChemistryProfile>, // overrides profile of chemistry
+}
+
+impl HealthConfig {
+    pub fn new(capacity: Ah, chemistry: Chemistry) -> Self {
+        Self {
+            capacity,
+            chemistry,
+            profile: None,
+        }
+    }
+
+    pub fn profile(&self) -> ChemistryProfile {
+        self.profile.unwrap_or_else(|| self.chemistry.profile())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub enum HealthWarning {
+    FullDischarges(u64), // H5 battery was fully discharged
+    DeepestDischargeAboveRecommended(Ratio),
+    AverageDischargeAboveRecommended(Ratio),
+    LowVoltageAlarms(u64),  // H11
+    HighVoltageAlarms(u64), // H12
+    EndOfLife,              // rated cycle life used up
+}
+
+/// Battery health interpreted from BMV history (H1..H18)
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub deepest_discharge: Option<Ratio>,    // [0..1] H1 relative to capacity
+    pub last_discharge: Option<Ratio>,       // [0..1] H2 relative to capacity
+    pub average_discharge: Option<Ratio>,    // [0..1] H3 relative to capacity
+    pub charge_cycles: Option<f64>,          // H4
+    pub equivalent_full_cycles: Option<f64>, // H6 relative to capacity
+    pub charge_efficiency: Option<Ratio>,    // [0..1] discharged (H17) to charged (H18) energy
+    pub rated_cycles: Option<f64>,           // cycle life at average depth of discharge
+    pub remaining_cycles: Option<f64>,       // cycles at average depth of discharge left
+    pub remaining_life: Option<Ratio>,       // [0..1] part of cycle life left
+    pub warnings: Vec<HealthWarning>,
+}
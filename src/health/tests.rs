@@ -0,0 +1,71 @@
+use super::*;
+use crate::test_utils::{data_from, round};
+use strum::IntoEnumIterator;
+
+#[test]
+fn cycle_life_test() {
+    for chemistry in Chemistry::iter() {
+        let profile = chemistry.profile();
+        assert_eq!(cycle_life(&profile, 1.0).unwrap(), profile.full_cycles);
+        assert!(cycle_life(&profile, 0.3).unwrap() > cycle_life(&profile, 0.5).unwrap());
+        assert_eq!(cycle_life(&profile, 0.0), None);
+    }
+    assert_eq!(cycle_life(&Chemistry::LiFePO4.profile(), 0.5).unwrap(), 6000.0);
+}
+
+#[test]
+fn battery_health_test() {
+    let data = data_from(&[
+        ("H1", "-150000"),
+        ("H2", "-40000"),
+        ("H3", "-50000"),
+        ("H4", "120"),
+        ("H5", "2"),
+        ("H6", "-3000000"),
+        ("H11", "0"),
+        ("H12", "1"),
+        ("H17", "8000"),
+        ("H18", "10000"),
+    ]);
+    let report = battery_health(&data, &HealthConfig::new(200.0, Chemistry::LiFePO4));
+
+    assert_eq!(report.deepest_discharge.unwrap(), 0.75);
+    assert_eq!(report.last_discharge.unwrap(), 0.2);
+    assert_eq!(report.average_discharge.unwrap(), 0.25);
+    assert_eq!(report.charge_cycles.unwrap(), 120.0);
+    assert_eq!(report.equivalent_full_cycles.unwrap(), 15.0);
+    assert_eq!(report.charge_efficiency.unwrap(), 0.8);
+    assert_eq!(report.rated_cycles.unwrap(), 12000.0);
+    assert_eq!(report.remaining_cycles.unwrap(), 11940.0);
+    assert_eq!(round(report.remaining_life.unwrap()), 0.995);
+    assert_eq!(report.warnings, vec![HealthWarning::FullDischarges(2), HealthWarning::HighVoltageAlarms(1)]);
+
+    let report = battery_health(&data, &HealthConfig::new(200.0, Chemistry::Agm));
+    assert!(report
+        .warnings
+        .contains(&HealthWarning::DeepestDischargeAboveRecommended(0.75)));
+}
+
+#[test]
+fn end_of_life_test() {
+    let data = data_from(&[("H3", "-100000"), ("H6", "-40000000")]);
+    let mut config = HealthConfig::new(100.0, Chemistry::FloodedLeadAcid);
+    config.profile = Some(ChemistryProfile {
+        full_cycles: 300.0,
+        exponent: 1.0,
+        recommended_dod: 1.0,
+    });
+
+    let report = battery_health(&data, &config);
+
+    assert_eq!(report.rated_cycles.unwrap(), 300.0);
+    assert_eq!(report.remaining_cycles.unwrap(), 0.0);
+    assert_eq!(report.remaining_life.unwrap(), 0.0);
+    assert_eq!(report.warnings, vec![HealthWarning::EndOfLife]);
+}
+
+#[test]
+fn missing_history_test() {
+    let report = battery_health(&data_from(&[("V", "12540")]), &HealthConfig::new(100.0, Chemistry::Gel));
+    assert_eq!(report, HealthReport::default());
+}
@@ -0,0 +1,201 @@
+//! # Example
+//! ```
+//! // converted variable from converter output of solar charger
+//! // dates are resolved in local time of the installation
+//! let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+//! if let Some([today, yesterday]) = history_days(&converted, &tz) {
+//!     println!("{}: {:?} Wh, {}: {:?} Wh", today.date, today.yield_energy, yesterday.date, yesterday.yield_energy);
+//! }
+//! let mut tracker = HistoryTracker::new(tz);
+//! for day in tracker.update(&converted) {
+//!     println!("finished {}: {:?} Wh", day.date, day.yield_energy);
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::VEDirectData;
+use chrono::{Duration, FixedOffset, NaiveDate, TimeZone};
+use std::collections::BTreeMap;
+
+/// returns true when day sequence number went back to the start of the counter between two readings
+pub fn hsds_wrapped(previous: u16, current: u16) -> bool {
+    current < previous
+}
+
+/// number of days `hsds` lies before `current_hsds`, taking wrap-around into account
+pub fn days_before(current_hsds: u16, hsds: u16) -> u16 {
+    (current_hsds % HSDS_DAYS + HSDS_DAYS - hsds % HSDS_DAYS) % HSDS_DAYS
+}
+
+/// resolves day sequence number to date, given the date at which device reported `current_hsds`
+pub fn resolve_date(reference: NaiveDate, current_hsds: u16, hsds: u16) -> Option<NaiveDate> {
+    reference.checked_sub_signed(Duration::days(days_before(current_hsds, hsds) as i64))
+}
+
+/// calendar date of unix timestamp in given timezone
+pub fn local_date<Tz: TimeZone>(time: i64, tz: &Tz) -> Option<NaiveDate> {
+    Some(tz.timestamp_opt(time, 0).single()?.date_naive())
+}
+
+/// resolves today (H20, H21) and yesterday (H22, H23) records of packet to dates,
+/// `rollover_date` is the local date at which device last rolled HSDS over, i.e. the date of its current day
+pub fn history_days_at(data: &VEDirectData, rollover_date: NaiveDate) -> Option<[HistoryDay; 2]> {
+    let hsds = data.HSDS? as u16 % HSDS_DAYS;
+    let yesterday_hsds = (hsds + HSDS_DAYS - 1) % HSDS_DAYS;
+    Some([
+        HistoryDay {
+            date: rollover_date,
+            day_sequence: hsds,
+            yield_energy: data.H20,
+            max_power: data.H21,
+            wrapped: false,
+        },
+        HistoryDay {
+            date: resolve_date(rollover_date, hsds, yesterday_hsds)?,
+            day_sequence: yesterday_hsds,
+            yield_energy: data.H22,
+            max_power: data.H23,
+            wrapped: hsds_wrapped(yesterday_hsds, hsds),
+        },
+    ])
+}
+
+/// resolves history records of packet using date of its Time field in timezone of the installation;
+/// dates are only valid after device rolled over that day: between midnight and the morning rollover
+/// they are one day late, use `HistoryTracker` or `history_days_at` with the rollover date instead
+pub fn history_days<Tz: TimeZone>(data: &VEDirectData, tz: &Tz) -> Option<[HistoryDay; 2]> {
+    history_days_at(data, local_date(data.Time?, tz)?)
+}
+
+/// Records finalised days of solar charger history. Day change is detected from HSDS rather than
+/// clock, so a day keeps its date until the device rolls it over. Yesterday record (H22, H23)
+/// of every frame fills days missing in the store, e.g. when logger was offline at the day change.
+/// Dates counted from HSDS are checked against the local date of Time and re-anchored to it when they
+/// disagree by more than a day: device reset or replacement, or logger offline for a year or longer.
+/// Without stored days the date of the device day is guessed from clock, which is a day late between
+/// midnight and the morning rollover; nothing is finalised until the first rollover confirms the date.
+#[derive(PartialEq, Debug, Clone)]
+pub struct HistoryTracker {
+    offset: FixedOffset, // timezone of the installation
+    days: BTreeMap<NaiveDate, HistoryDay>,
+    anchor: Option<(u16, NaiveDate)>, // day sequence number and its date
+    confirmed: bool,                  // anchor date counted from stored day or rollover rather than guessed
+    today: Option<HistoryDay>,
+}
+
+impl HistoryTracker {
+    pub fn new(offset: FixedOffset) -> Self {
+        Self::resume(BTreeMap::new(), offset)
+    }
+
+    /// continues tracking with previously stored days, the latest of them anchors dates of first frame
+    pub fn resume(days: BTreeMap<NaiveDate, HistoryDay>, offset: FixedOffset) -> Self {
+        Self {
+            offset,
+            days,
+            anchor: None,
+            confirmed: false,
+            today: None,
+        }
+    }
+
+    /// date of `hsds` counted from the latest stored day
+    fn resumed_date(&self, hsds: u16) -> Option<NaiveDate> {
+        let (date, day) = self.days.iter().next_back()?;
+        let days = days_before(hsds, day.day_sequence);
+        // same sequence number as finalised day means counter started over
+        if days == 0 {
+            return None;
+        }
+        date.checked_add_signed(Duration::days(days as i64))
+    }
+
+    /// processes frame of solar charger, returns days finalised by it in date order;
+    /// frames without HSDS or Time are skipped
+    pub fn update(&mut self, data: &VEDirectData) -> Vec<HistoryDay> {
+        let mut finalised = Vec::new();
+        let (hsds, clock_date) = match (data.HSDS, data.Time.and_then(|time| local_date(time, &self.offset))) {
+            (Some(hsds), Some(clock_date)) => (hsds as u16 % HSDS_DAYS, clock_date),
+            _ => return finalised,
+        };
+        let (date, confirmed) = match self.anchor {
+            Some((anchor_hsds, anchor_date)) => {
+                (anchor_date.checked_add_signed(Duration::days(days_before(hsds, anchor_hsds) as i64)), self.confirmed)
+            }
+            None => (self.resumed_date(hsds), true),
+        };
+        let rolled_over = matches!(self.anchor, Some((anchor_hsds, _)) if anchor_hsds != hsds);
+        // device day may lag behind clock until it rolls over in the morning
+        let (date, confirmed) = match date {
+            Some(date) if (date - clock_date).num_days().abs() <= 1 => {
+                if !confirmed && rolled_over {
+                    // device has just started a new day, so it is the clock date; guessed day moves along
+                    if let Some(previous) = self.today.as_mut() {
+                        previous.date += clock_date - date;
+                    }
+                    (clock_date, true)
+                } else {
+                    (date, confirmed)
+                }
+            }
+            _ => (clock_date, false),
+        };
+        let previous_confirmed = self.confirmed || confirmed;
+        self.anchor = Some((hsds, date));
+        self.confirmed = confirmed;
+
+        let yesterday_hsds = (hsds + HSDS_DAYS - 1) % HSDS_DAYS;
+        if let Some(yesterday_date) = date.pred_opt().filter(|_| confirmed) {
+            if (data.H22.is_some() || data.H23.is_some()) && !self.days.contains_key(&yesterday_date) {
+                finalised.push(HistoryDay {
+                    date: yesterday_date,
+                    day_sequence: yesterday_hsds,
+                    yield_energy: data.H22,
+                    max_power: data.H23,
+                    wrapped: hsds_wrapped(yesterday_hsds, hsds),
+                });
+            }
+        }
+        // day before a gap of several days is finalised with the last values seen
+        if let Some(previous) = self
+            .today
+            .take()
+            .filter(|previous| previous_confirmed && previous.date < date)
+        {
+            if !self.days.contains_key(&previous.date) && !finalised.iter().any(|day| day.date == previous.date) {
+                finalised.insert(0, previous);
+            }
+        }
+        self.today = Some(HistoryDay {
+            date,
+            day_sequence: hsds,
+            yield_energy: data.H20,
+            max_power: data.H21,
+            wrapped: false,
+        });
+
+        for day in finalised.iter() {
+            self.days.insert(day.date, day.clone());
+        }
+        finalised
+    }
+
+    /// finalised days
+    pub fn days(&self) -> &BTreeMap<NaiveDate, HistoryDay> {
+        &self.days
+    }
+
+    /// current day as of last frame, not finalised yet; its date is guessed from clock until first rollover
+    pub fn today(&self) -> Option<&HistoryDay> {
+        self.today.as_ref()
+    }
+
+    pub fn into_days(self) -> BTreeMap<NaiveDate, HistoryDay> {
+        self.days
+    }
+}
@@ -0,0 +1,16 @@
+use crate::converter::models::{Wh, W};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Day sequence number counts 0..364 and wraps to 0 afterwards
+pub const HSDS_DAYS: u16 = 365;
+
+/// History record of a solar charger day resolved to calendar date
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryDay {
+    pub date: NaiveDate,
+    pub day_sequence: u16,
+    pub yield_energy: Option<Wh>, // [Wh] H20 or H22
+    pub max_power: Option<W>,     // [W] H21 or H23
+    pub wrapped: bool,            // day belongs to previous cycle of day sequence counter
+}
@@ -0,0 +1,207 @@
+use super::*;
+use crate::test_utils::{data_from, frame};
+use chrono::{FixedOffset, Utc};
+
+fn mppt(hsds: &str, time: i64) -> VEDirectData {
+    frame(time, &[("H20", "1"), ("H21", "6"), ("H22", "4"), ("H23", "14"), ("HSDS", hsds)])
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn days_before_test() {
+    assert_eq!(days_before(16, 16), 0);
+    assert_eq!(days_before(16, 15), 1);
+    assert_eq!(days_before(16, 0), 16);
+    assert_eq!(days_before(0, 364), 1);
+    assert_eq!(days_before(2, 360), 7);
+}
+
+#[test]
+fn resolve_date_test() {
+    assert_eq!(resolve_date(date(2022, 8, 10), 16, 14).unwrap(), date(2022, 8, 8));
+    assert_eq!(resolve_date(date(2022, 1, 1), 0, 364).unwrap(), date(2021, 12, 31));
+    assert_eq!(resolve_date(date(2022, 3, 1), 10, 10).unwrap(), date(2022, 3, 1));
+}
+
+#[test]
+fn hsds_wrapped_test() {
+    assert!(hsds_wrapped(364, 0));
+    assert!(!hsds_wrapped(15, 16));
+    assert!(!hsds_wrapped(16, 16));
+}
+
+#[test]
+fn history_days_test() {
+    // 2022-08-10 12:00:00 UTC
+    let [today, yesterday] = history_days(&mppt("16", 1660132800), &Utc).unwrap();
+    assert_eq!(today.date, date(2022, 8, 10));
+    assert_eq!(today.day_sequence, 16);
+    assert_eq!(today.yield_energy.unwrap(), 10.0);
+    assert_eq!(today.max_power.unwrap(), 6.0);
+    assert!(!today.wrapped);
+    assert_eq!(yesterday.date, date(2022, 8, 9));
+    assert_eq!(yesterday.day_sequence, 15);
+    assert_eq!(yesterday.yield_energy.unwrap(), 40.0);
+    assert_eq!(yesterday.max_power.unwrap(), 14.0);
+    assert!(!yesterday.wrapped);
+}
+
+#[test]
+fn history_days_wrap_test() {
+    let [today, yesterday] = history_days(&mppt("0", 1660132800), &Utc).unwrap();
+    assert_eq!(today.day_sequence, 0);
+    assert_eq!(yesterday.day_sequence, 364);
+    assert_eq!(yesterday.date, date(2022, 8, 9));
+    assert!(yesterday.wrapped);
+}
+
+#[test]
+fn history_days_missing_test() {
+    let data = data_from(&[("H20", "1")]);
+    assert_eq!(history_days(&data, &Utc), None);
+    assert_eq!(history_days_at(&data, date(2022, 8, 10)), None);
+}
+
+#[test]
+fn history_days_timezone_test() {
+    // 2022-08-10 20:00:00 UTC is already next day east of UTC
+    let data = mppt("16", 1660161600);
+    let [today, yesterday] = history_days(&data, &FixedOffset::east_opt(6 * 3600).unwrap()).unwrap();
+    assert_eq!(today.date, date(2022, 8, 11));
+    assert_eq!(yesterday.date, date(2022, 8, 10));
+    let [today, _] = history_days(&data, &FixedOffset::west_opt(6 * 3600).unwrap()).unwrap();
+    assert_eq!(today.date, date(2022, 8, 10));
+    assert_eq!(history_days_at(&data, date(2022, 8, 12)).unwrap()[1].date, date(2022, 8, 11));
+}
+
+fn mppt_day(hsds: &str, time: i64, today: &str, yesterday: &str) -> VEDirectData {
+    frame(
+        time,
+        &[
+            ("H20", today),
+            ("H21", "100"),
+            ("H22", yesterday),
+            ("H23", "200"),
+            ("HSDS", hsds),
+        ],
+    )
+}
+
+// 2022-08-10 12:00:00 UTC
+const NOON: i64 = 1660132800;
+const DAY: i64 = 86400;
+
+/// tracker whose dates are confirmed by a stored day, at day 16 on 2022-08-10
+fn confirmed_tracker() -> HistoryTracker {
+    let mut days = BTreeMap::new();
+    let day = HistoryDay {
+        date: date(2022, 8, 9),
+        day_sequence: 15,
+        yield_energy: Some(400.0),
+        max_power: Some(200.0),
+        wrapped: false,
+    };
+    days.insert(day.date, day);
+    let mut tracker = HistoryTracker::resume(days, utc());
+    tracker.update(&mppt_day("16", NOON, "10", "40"));
+    tracker
+}
+
+#[test]
+fn tracker_rollover_test() {
+    let mut tracker = HistoryTracker::new(utc());
+
+    // yesterday is not finalised on start, date of device day is only guessed from clock
+    assert!(tracker.update(&mppt_day("16", NOON, "10", "40")).is_empty());
+    assert!(tracker.update(&mppt_day("16", NOON + 3600, "20", "40")).is_empty());
+
+    // past midnight UTC the device still reports the same day
+    assert!(tracker.update(&mppt_day("16", NOON + DAY / 2 + 600, "25", "40")).is_empty());
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 10));
+
+    let finalised = tracker.update(&mppt_day("17", NOON + DAY / 2 + 3600, "0", "26"));
+    assert_eq!(finalised.len(), 1);
+    assert_eq!(finalised[0].date, date(2022, 8, 10));
+    assert_eq!(finalised[0].day_sequence, 16);
+    assert_eq!(finalised[0].yield_energy.unwrap(), 260.0);
+    assert_eq!(finalised[0].max_power.unwrap(), 200.0);
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 11));
+    assert_eq!(tracker.days().len(), 1);
+}
+
+#[test]
+fn tracker_fresh_start_test() {
+    // started past midnight UTC before device rolled over, day is dated a day late
+    let mut tracker = HistoryTracker::new(utc());
+    assert!(tracker.update(&mppt_day("16", NOON + DAY / 2 + 600, "25", "40")).is_empty());
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 11));
+
+    // rollover in the morning dates the new day and the day before it
+    let finalised = tracker.update(&mppt_day("17", NOON + DAY / 2 + 6 * 3600, "0", "26"));
+    assert_eq!(finalised.iter().map(|day| day.date).collect::<Vec<_>>(), vec![date(2022, 8, 10)]);
+    assert_eq!(finalised[0].day_sequence, 16);
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 11));
+
+    let finalised = tracker.update(&mppt_day("18", NOON + 3 * DAY / 2 + 6 * 3600, "0", "30"));
+    assert_eq!(finalised.iter().map(|day| day.date).collect::<Vec<_>>(), vec![date(2022, 8, 11)]);
+}
+
+#[test]
+fn tracker_gap_test() {
+    let mut tracker = HistoryTracker::resume(BTreeMap::new(), utc());
+    tracker.update(&mppt_day("363", NOON, "10", "40"));
+
+    // logger offline for three days, across counter wrap
+    let finalised = tracker.update(&mppt_day("1", NOON + 3 * DAY, "5", "30"));
+    assert_eq!(finalised.iter().map(|day| day.date).collect::<Vec<_>>(), vec![date(2022, 8, 10), date(2022, 8, 12)]);
+    assert_eq!(finalised[0].yield_energy.unwrap(), 100.0);
+    assert_eq!(finalised[1].day_sequence, 0);
+    assert!(!finalised[1].wrapped);
+    assert!(!tracker.days().contains_key(&date(2022, 8, 11)));
+
+    // frames without HSDS are skipped
+    assert!(tracker.update(&data_from(&[("H20", "1")])).is_empty());
+}
+
+#[test]
+fn tracker_reanchor_test() {
+    // device replaced, counter starts over; yesterday is held back until dates are confirmed again
+    let mut tracker = confirmed_tracker();
+    let finalised = tracker.update(&mppt_day("3", NOON + DAY, "5", "30"));
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 11));
+    assert_eq!(finalised.iter().map(|day| day.date).collect::<Vec<_>>(), vec![date(2022, 8, 10)]);
+
+    // logger offline for more than a year, counter aliases to the next day
+    let mut tracker = confirmed_tracker();
+    let finalised = tracker.update(&mppt_day("17", NOON + 366 * DAY, "5", "30"));
+    assert_eq!(tracker.today().unwrap().date, date(2023, 8, 11));
+    assert_eq!(finalised.iter().map(|day| day.date).collect::<Vec<_>>(), vec![date(2022, 8, 10)]);
+}
+
+#[test]
+fn tracker_resume_test() {
+    let days = confirmed_tracker().into_days();
+
+    // restarted past midnight before device rolled over, day keeps its date
+    let mut tracker = HistoryTracker::resume(days, utc());
+    assert!(tracker.update(&mppt_day("16", NOON + DAY / 2 + 600, "25", "40")).is_empty());
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 10));
+    assert_eq!(tracker.days().len(), 1);
+}
+
+#[test]
+fn tracker_timezone_test() {
+    // 2022-08-10 23:00 UTC is 2022-08-11 09:00 ten hours east
+    let mut tracker = HistoryTracker::new(FixedOffset::east_opt(10 * 3600).unwrap());
+    tracker.update(&mppt_day("16", NOON - 3600, "10", "40"));
+    let finalised = tracker.update(&mppt_day("17", NOON + 11 * 3600, "5", "30"));
+    assert_eq!(tracker.today().unwrap().date, date(2022, 8, 11));
+    assert_eq!(finalised[0].date, date(2022, 8, 10));
+}
@@ -0,0 +1,180 @@
+//! # Example
+//! ```
+//! let mut integrator = EnergyIntegrator::new(Duration::from_secs(30));
+//! // converted variable from converter output, Time is set by parser
+//! integrator.push(&converted);
+//! println!("discharged {} Wh", integrator.totals().battery_discharged);
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::VEDirectData;
+use crate::derived::{battery_power, load_power};
+use std::time::Duration;
+
+/// Values of frame taking part in integration: battery power, battery current, pv power,
+/// load power, load current and ac output power
+type Values = [Option<f64>; 6];
+
+fn values(data: &VEDirectData) -> Values {
+    [
+        battery_power(data),
+        data.I,
+        data.PPV,
+        load_power(data),
+        data.IL,
+        data.AC_OUT_S,
+    ]
+}
+
+/// area under straight line from `a` to `b` over `hours` split into positive and negative part
+fn trapezoid(a: f64, b: f64, hours: f64) -> (f64, f64) {
+    if a >= 0_f64 && b >= 0_f64 {
+        ((a + b) / 2_f64 * hours, 0_f64)
+    } else if a <= 0_f64 && b <= 0_f64 {
+        (0_f64, -(a + b) / 2_f64 * hours)
+    } else {
+        // line crosses zero, each side is a triangle
+        let crossing = a / (a - b) * hours;
+        let first = a * crossing / 2_f64;
+        let second = b * (hours - crossing) / 2_f64;
+        if a > 0_f64 {
+            (first, -second)
+        } else {
+            (second, -first)
+        }
+    }
+}
+
+/// Integrates power and current of successive frames using trapezoidal rule.
+/// Last value of each quantity is kept separately, so devices sending their fields in several
+/// blocks (battery monitor main and history block) are integrated between frames carrying the value.
+#[derive(PartialEq, Debug, Clone)]
+pub struct EnergyIntegrator {
+    totals: EnergyTotals,
+    max_gap: Duration,
+    last_time: Option<i64>,        // [s] time of last frame with any integrable value
+    last: [Option<(i64, f64)>; 6], // time and value of each quantity when it was last seen
+}
+
+impl Default for EnergyIntegrator {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_MAX_GAP))
+    }
+}
+
+impl EnergyIntegrator {
+    pub fn new(max_gap: Duration) -> Self {
+        Self::resume(EnergyTotals::default(), max_gap)
+    }
+
+    /// continues integration from previously persisted totals
+    pub fn resume(totals: EnergyTotals, max_gap: Duration) -> Self {
+        Self {
+            totals,
+            max_gap,
+            last_time: None,
+            last: [None; 6],
+        }
+    }
+
+    /// integrates interval between previous and given frame, returns false when nothing was integrated:
+    /// frame without Time or integrable values, first frame, frame older than previous one or interval longer than max gap
+    pub fn push(&mut self, data: &VEDirectData) -> bool {
+        let time = match data.Time {
+            Some(time) => time,
+            None => return false,
+        };
+        let values = values(data);
+        // e.g. history block of battery monitor, it neither ends nor starts an interval
+        if values.iter().all(Option::is_none) {
+            return false;
+        }
+        let last_time = match self.last_time {
+            Some(last_time) => last_time,
+            None => {
+                self.store(time, &values);
+                return false;
+            }
+        };
+        if time <= last_time {
+            return false;
+        }
+
+        let seconds = (time - last_time) as u64;
+        let max_gap = self.max_gap.as_secs();
+        if seconds > max_gap {
+            self.totals.gaps += 1;
+            self.last = [None; 6];
+            self.store(time, &values);
+            return false;
+        }
+
+        let mut integrated = [None; 6];
+        for (area, (last, value)) in integrated.iter_mut().zip(self.last.iter().zip(values.iter())) {
+            if let (Some((last_time, last_value)), Some(value)) = (last, value) {
+                // quantity may be missing in frames in between, its own interval is integrated
+                let seconds = (time - last_time) as u64;
+                if seconds <= max_gap {
+                    *area = Some(trapezoid(*last_value, *value, seconds as f64 / 3600_f64));
+                }
+            }
+        }
+        self.store(time, &values);
+        if integrated.iter().all(Option::is_none) {
+            return false;
+        }
+
+        let [battery_power, battery_current, pv_power, load_power, load_current, ac_power] = integrated;
+        let totals = &mut self.totals;
+        if let Some((charged, discharged)) = battery_power {
+            totals.battery_charged += charged;
+            totals.battery_discharged += discharged;
+        }
+        if let Some((charge_in, charge_out)) = battery_current {
+            totals.charge_in += charge_in;
+            totals.charge_out += charge_out;
+        }
+        if let Some((pv_yield, _)) = pv_power {
+            totals.pv_yield += pv_yield;
+        }
+        if let Some((load_consumed, _)) = load_power {
+            totals.load_consumed += load_consumed;
+        }
+        if let Some((load_charge, _)) = load_current {
+            totals.load_charge += load_charge;
+        }
+        if let Some((ac_out, _)) = ac_power {
+            totals.ac_out += ac_out;
+        }
+        totals.integrated_seconds += seconds;
+        true
+    }
+
+    fn store(&mut self, time: i64, values: &Values) {
+        self.last_time = Some(time);
+        for (last, value) in self.last.iter_mut().zip(values.iter()) {
+            if let Some(value) = value {
+                *last = Some((time, *value));
+            }
+        }
+    }
+
+    pub fn totals(&self) -> &EnergyTotals {
+        &self.totals
+    }
+
+    /// totals for persisting, integration continues from them after `resume`
+    pub fn into_totals(self) -> EnergyTotals {
+        self.totals
+    }
+
+    /// clears totals, keeps last frame so integration continues seamlessly
+    pub fn reset(&mut self) -> EnergyTotals {
+        std::mem::take(&mut self.totals)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::converter::models::{Ah, Wh};
+use serde::{Deserialize, Serialize};
+
+/// Frames further apart are not integrated, the interval is counted as a gap instead
+pub const DEFAULT_MAX_GAP: u64 = 60; // [s]
+
+/// Running totals of energy integrator, can be persisted and passed to `EnergyIntegrator::resume`
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnergyTotals {
+    pub battery_charged: Wh,     // [Wh] V × I while I > 0
+    pub battery_discharged: Wh,  // [Wh] V × I while I < 0
+    pub charge_in: Ah,           // [Ah] I while I > 0
+    pub charge_out: Ah,          // [Ah] I while I < 0
+    pub pv_yield: Wh,            // [Wh] PPV
+    pub load_consumed: Wh,       // [Wh] V × IL
+    pub load_charge: Ah,         // [Ah] IL
+    pub ac_out: f64,             // [VAh] AC_OUT_S
+    pub integrated_seconds: u64, // [s] time covered by integrated intervals
+    pub gaps: u64,               // intervals skipped because frames were too far apart
+}
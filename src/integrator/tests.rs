@@ -0,0 +1,89 @@
+use super::*;
+use crate::test_utils::{data_from, frame, round};
+
+#[test]
+fn trapezoid_test() {
+    assert_eq!(trapezoid(2.0, 4.0, 1.0), (3.0, 0.0));
+    assert_eq!(trapezoid(-2.0, -4.0, 1.0), (0.0, 3.0));
+    assert_eq!(trapezoid(2.0, -2.0, 1.0), (0.5, 0.5));
+    assert_eq!(trapezoid(-3.0, 1.0, 1.0), (0.125, 1.125));
+}
+
+#[test]
+fn integrate_test() {
+    let mut integrator = EnergyIntegrator::default();
+
+    assert!(!integrator.push(&frame(0, &[("V", "12000"), ("I", "10000"), ("PPV", "150")])));
+    assert!(integrator.push(&frame(36, &[("V", "12000"), ("I", "10000"), ("PPV", "150")])));
+    assert!(integrator.push(&frame(72, &[("V", "12000"), ("I", "-10000"), ("PPV", "50")])));
+
+    let totals = integrator.totals();
+    assert_eq!(round(totals.battery_charged), 1.5);
+    assert_eq!(round(totals.battery_discharged), 0.3);
+    assert_eq!(round(totals.charge_in), 0.125);
+    assert_eq!(round(totals.charge_out), 0.025);
+    assert_eq!(round(totals.pv_yield), 2.5);
+    assert_eq!(totals.load_consumed, 0.0);
+    assert_eq!(totals.integrated_seconds, 72);
+}
+
+#[test]
+fn interleaved_blocks_test() {
+    let mut integrator = EnergyIntegrator::default();
+    let history = [("H1", "-10000"), ("H2", "-5000"), ("H4", "12")];
+
+    assert!(!integrator.push(&frame(0, &[("V", "12000"), ("I", "-10000"), ("P", "-120")])));
+    assert!(!integrator.push(&frame(1, &history)));
+    assert!(integrator.push(&frame(2, &[("V", "12000"), ("I", "-10000"), ("P", "-120")])));
+    assert!(!integrator.push(&frame(3, &history)));
+    assert!(integrator.push(&frame(4, &[("V", "12000"), ("I", "-10000"), ("P", "-120")])));
+    assert!(!integrator.push(&frame(5, &history)));
+
+    let totals = integrator.totals();
+    assert_eq!(round(totals.battery_discharged * 3600_f64), 480.0);
+    assert_eq!(round(totals.charge_out * 3600_f64), 40.0);
+    assert_eq!(totals.integrated_seconds, 4);
+    assert_eq!(totals.gaps, 0);
+
+    // quantity missing in some frames is integrated over its own interval
+    let mut integrator = EnergyIntegrator::default();
+    integrator.push(&frame(0, &[("V", "12000"), ("I", "1000"), ("PPV", "100")]));
+    assert!(integrator.push(&frame(2, &[("PPV", "100")])));
+    assert!(integrator.push(&frame(4, &[("V", "12000"), ("I", "1000"), ("PPV", "100")])));
+    let totals = integrator.totals();
+    assert_eq!(round(totals.charge_in * 3600_f64), 4.0);
+    assert_eq!(round(totals.pv_yield * 3600_f64), 400.0);
+    assert_eq!(totals.integrated_seconds, 4);
+}
+
+#[test]
+fn gap_test() {
+    let mut integrator = EnergyIntegrator::new(Duration::from_secs(10));
+
+    integrator.push(&frame(100, &[("PPV", "100")]));
+    assert!(!integrator.push(&frame(100, &[("PPV", "100")])));
+    assert!(!integrator.push(&frame(90, &[("PPV", "100")])));
+    assert!(!integrator.push(&frame(200, &[("PPV", "100")])));
+    assert!(!integrator.push(&frame(236, &[("PPV", "100")])));
+    assert!(!integrator.push(&data_from(&[("PPV", "100")])));
+
+    let totals = integrator.totals();
+    assert_eq!(totals.gaps, 2);
+    assert_eq!(totals.pv_yield, 0.0);
+}
+
+#[test]
+fn resume_test() {
+    let mut integrator = EnergyIntegrator::default();
+    integrator.push(&frame(0, &[("V", "12000"), ("IL", "5000")]));
+    integrator.push(&frame(60, &[("V", "12000"), ("IL", "5000")]));
+
+    let json = serde_json::to_string(&integrator.into_totals()).unwrap();
+    let mut integrator = EnergyIntegrator::resume(serde_json::from_str(&json).unwrap(), Duration::from_secs(60));
+    integrator.push(&frame(1000, &[("V", "12000"), ("IL", "5000")]));
+    integrator.push(&frame(1060, &[("V", "12000"), ("IL", "5000")]));
+
+    assert_eq!(round(integrator.totals().load_consumed), 2.0);
+    assert_eq!(round(integrator.reset().load_charge), 0.167);
+    assert_eq!(integrator.totals().load_consumed, 0.0);
+}
@@ -35,13 +35,64 @@
 //! }
 //! ```
 
+pub mod aggregator;
+pub mod alerts;
+pub mod charge;
 pub mod converter;
+pub mod derived;
+pub mod device;
+pub mod diff;
+pub mod health;
+pub mod history;
+pub mod integrator;
+pub mod locale;
+pub mod metadata;
 pub mod parser;
+pub mod product;
+pub mod soc;
+pub mod validation;
+pub mod watchdog;
 
+#[cfg(test)]
+mod test_utils;
 #[cfg(test)]
 mod tests;
 
-pub use self::converter::convert;
+pub use self::aggregator::models::*;
+pub use self::aggregator::Aggregator;
+pub use self::alerts::models::*;
+pub use self::alerts::AlertEngine;
+pub use self::charge::models::*;
+pub use self::charge::ChargeTracker;
+pub use self::converter::exact::{convert_exact, VEDirectExact};
 pub use self::converter::models::*;
+#[cfg(feature = "uom")]
+pub use self::converter::quantities::VEDirectQuantities;
+pub use self::converter::registry::ConverterRegistry;
+pub use self::converter::v1::{convert_v1, VEDirectDataV1};
+pub use self::converter::{convert, revert};
+pub use self::derived::derived_metrics;
+pub use self::derived::models::*;
+pub use self::device::classify;
+pub use self::device::models::*;
+pub use self::diff::models::*;
+pub use self::diff::{diff, ChangeDetector};
+pub use self::health::battery_health;
+pub use self::health::models::*;
+pub use self::history::models::*;
+pub use self::history::{history_days, HistoryTracker};
+pub use self::integrator::models::*;
+pub use self::integrator::EnergyIntegrator;
+pub use self::locale::models::{Describe, Locale};
+pub use self::metadata::models::{DataType, LabelMetadata, Unit};
+pub use self::metadata::{label_metadata, registry};
 pub use self::parser::models::*;
 pub use self::parser::Parser;
+pub use self::product::models::*;
+pub use self::product::{firmware_version, product_info, serial_number};
+pub use self::soc::models::*;
+pub use self::soc::SocEstimator;
+pub use self::validation::models::*;
+pub use self::validation::Validator;
+pub use self::watchdog::models::*;
+pub use self::watchdog::Watchdog;
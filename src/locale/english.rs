@@ -0,0 +1,211 @@
+/// Built-in english translations, also used as fallback for other locales
+pub static ENGLISH: &[(&str, &str)] = &[
+    // label names
+    ("label.V", "Main (battery) voltage"),
+    ("label.VS", "Auxiliary (starter) voltage"),
+    ("label.V2", "Channel 2 (battery) voltage"),
+    ("label.V3", "Channel 3 (battery) voltage"),
+    ("label.VM", "Mid-point voltage of the battery bank"),
+    ("label.DM", "Mid-point deviation of the battery bank"),
+    ("label.VPV", "Panel voltage"),
+    ("label.PPV", "Panel power"),
+    ("label.I", "Battery current"),
+    ("label.I2", "Channel 2 battery current"),
+    ("label.I3", "Channel 3 battery current"),
+    ("label.IL", "Load current"),
+    ("label.LOAD", "Load output state"),
+    ("label.T", "Battery temperature"),
+    ("label.P", "Instantaneous power"),
+    ("label.CE", "Consumed amp hours"),
+    ("label.SOC", "State of charge"),
+    ("label.TTG", "Time to go"),
+    ("label.Alarm", "Alarm condition active"),
+    ("label.Relay", "Relay state"),
+    ("label.AR", "Alarm reason"),
+    ("label.OR", "Off reason"),
+    ("label.H1", "Depth of the deepest discharge"),
+    ("label.H2", "Depth of the last discharge"),
+    ("label.H3", "Depth of the average discharge"),
+    ("label.H4", "Number of charge cycles"),
+    ("label.H5", "Number of full discharges"),
+    ("label.H6", "Cumulative amp hours drawn"),
+    ("label.H7", "Minimum main (battery) voltage"),
+    ("label.H8", "Maximum main (battery) voltage"),
+    ("label.H9", "Time since last full charge"),
+    ("label.H10", "Number of automatic synchronizations"),
+    ("label.H11", "Number of low main voltage alarms"),
+    ("label.H12", "Number of high main voltage alarms"),
+    ("label.H13", "Number of low auxiliary voltage alarms"),
+    ("label.H14", "Number of high auxiliary voltage alarms"),
+    ("label.H15", "Minimum auxiliary (battery) voltage"),
+    ("label.H16", "Maximum auxiliary (battery) voltage"),
+    ("label.H17", "Amount of discharged energy"),
+    ("label.H18", "Amount of charged energy"),
+    ("label.H19", "Yield total"),
+    ("label.H20", "Yield today"),
+    ("label.H21", "Maximum power today"),
+    ("label.H22", "Yield yesterday"),
+    ("label.H23", "Maximum power yesterday"),
+    ("label.ERR", "Error code"),
+    ("label.CS", "State of operation"),
+    ("label.BMV", "Model description"),
+    ("label.FW", "Firmware version"),
+    ("label.FWE", "Firmware version"),
+    ("label.PID", "Product ID"),
+    ("label.SER#", "Serial number"),
+    ("label.HSDS", "Day sequence number"),
+    ("label.MODE", "Device mode"),
+    ("label.MPPT", "Tracker operation mode"),
+    ("label.MON", "DC monitor mode"),
+    ("label.AC_OUT_V", "AC output voltage"),
+    ("label.AC_OUT_I", "AC output current"),
+    ("label.AC_OUT_S", "AC output apparent power"),
+    ("label.DC_IN_V", "DC input voltage"),
+    ("label.DC_IN_I", "DC input current"),
+    ("label.DC_IN_P", "DC input power"),
+    ("label.WARN", "Warning reason"),
+    ("label.BLE", "Bluetooth status"),
+    ("label.CAP_BLE", "Bluetooth capabilities"),
+    ("label.Checksum", "Checksum"),
+    ("label.Calc_sum", "Calculated checksum"),
+    ("label.Unknown", "Unknown fields"),
+    ("label.Extensions", "Fields converted by custom converters"),
+    ("label.Time", "Timestamp"),
+    // units of converted values
+    ("unit.Volt", "V"),
+    ("unit.Ampere", "A"),
+    ("unit.Watt", "W"),
+    ("unit.VoltAmpere", "VA"),
+    ("unit.AmpereHour", "Ah"),
+    ("unit.WattHour", "Wh"),
+    ("unit.DegreeCelsius", "°C"),
+    ("unit.Second", "s"),
+    ("unit.Ratio", "fraction"),
+    // StateOfOperation
+    ("StateOfOperation.Off", "Off"),
+    ("StateOfOperation.LowPower", "Low power"),
+    ("StateOfOperation.Fault", "Fault"),
+    ("StateOfOperation.Bulk", "Bulk"),
+    ("StateOfOperation.Absorption", "Absorption"),
+    ("StateOfOperation.Float", "Float"),
+    ("StateOfOperation.Storage", "Storage"),
+    ("StateOfOperation.Equalize", "Equalize (manual)"),
+    ("StateOfOperation.Inverting", "Inverting"),
+    ("StateOfOperation.PowerSupply", "Power supply"),
+    ("StateOfOperation.StartingUp", "Starting up"),
+    ("StateOfOperation.RepeatedAbsorption", "Repeated absorption"),
+    ("StateOfOperation.AutoEqualize", "Auto equalize / recondition"),
+    ("StateOfOperation.BatterySafe", "BatterySafe"),
+    ("StateOfOperation.ExternalControl", "External control"),
+    // ErrorCode
+    ("ErrorCode.None", "No error"),
+    ("ErrorCode.BatteryVoltageTooHigh", "Battery voltage too high"),
+    ("ErrorCode.ChargerTemperatureTooHigh", "Charger temperature too high"),
+    ("ErrorCode.ChargerOverCurrent", "Charger over current"),
+    ("ErrorCode.ChargerCurrentReversed", "Charger current reversed"),
+    ("ErrorCode.BulkTimeLimitExceeded", "Bulk time limit exceeded"),
+    ("ErrorCode.CurrentSensorIssue", "Current sensor issue"),
+    ("ErrorCode.TerminalsOverheated", "Terminals overheated"),
+    ("ErrorCode.ConverterIssue", "Converter issue"),
+    ("ErrorCode.InputVoltageTooHigh", "Input voltage too high (solar panel)"),
+    ("ErrorCode.InputCurrentTooHigh", "Input current too high (solar panel)"),
+    ("ErrorCode.InputShutdownBatVoltage", "Input shutdown (due to excessive battery voltage)"),
+    ("ErrorCode.InputShutdownCurrentFlow", "Input shutdown (due to current flow during off mode)"),
+    ("ErrorCode.LostComWithDevices", "Lost communication with one of devices"),
+    ("ErrorCode.SynchronisedChargingIssue", "Synchronised charging device configuration issue"),
+    ("ErrorCode.BMSConnectionLost", "BMS connection lost"),
+    ("ErrorCode.NetworkMisconfigured", "Network misconfigured"),
+    ("ErrorCode.FactoryCalibrationDataLost", "Factory calibration data lost"),
+    ("ErrorCode.InvalidFirmware", "Invalid or incompatible firmware"),
+    ("ErrorCode.UserSettingsInvalid", "User settings invalid"),
+    // OffReason
+    ("OffReason.None", "None"),
+    ("OffReason.NoInputPower", "No input power"),
+    ("OffReason.SwitchedOffPowerSwitch", "Switched off (power switch)"),
+    ("OffReason.SwitchedOffDMR", "Switched off (device mode register)"),
+    ("OffReason.RemoteInput", "Remote input"),
+    ("OffReason.ProtectionActive", "Protection active"),
+    ("OffReason.Paygo", "Pay-as-you-go"),
+    ("OffReason.BMS", "BMS"),
+    ("OffReason.EngineShutdownDetection", "Engine shutdown detection"),
+    ("OffReason.AnalysingInputVoltage", "Analysing input voltage"),
+    // AlarmReason
+    ("AlarmReason.None", "None"),
+    ("AlarmReason.LowVoltage", "Low voltage"),
+    ("AlarmReason.HighVoltage", "High voltage"),
+    ("AlarmReason.LowSOC", "Low state of charge"),
+    ("AlarmReason.LowStarterVoltage", "Low starter voltage"),
+    ("AlarmReason.HighStarterVoltage", "High starter voltage"),
+    ("AlarmReason.LowTemperature", "Low temperature"),
+    ("AlarmReason.HighTemperature", "High temperature"),
+    ("AlarmReason.MidVoltage", "Mid-point voltage"),
+    ("AlarmReason.Overload", "Overload"),
+    ("AlarmReason.DCripple", "DC ripple"),
+    ("AlarmReason.LowVACout", "Low AC output voltage"),
+    ("AlarmReason.HighVACout", "High AC output voltage"),
+    // WarningReason
+    ("WarningReason.None", "None"),
+    ("WarningReason.LowVoltage", "Low voltage"),
+    ("WarningReason.HighVoltage", "High voltage"),
+    ("WarningReason.LowSOC", "Low state of charge"),
+    ("WarningReason.LowStarterVoltage", "Low starter voltage"),
+    ("WarningReason.HighStarterVoltage", "High starter voltage"),
+    ("WarningReason.LowTemperature", "Low temperature"),
+    ("WarningReason.HighTemperature", "High temperature"),
+    ("WarningReason.MidVoltage", "Mid-point voltage"),
+    ("WarningReason.Overload", "Overload"),
+    ("WarningReason.DCripple", "DC ripple"),
+    ("WarningReason.LowVACout", "Low AC output voltage"),
+    ("WarningReason.HighVACout", "High AC output voltage"),
+    // DeviceMode
+    ("DeviceMode.None", "None"),
+    ("DeviceMode.VE_REG_MODE_INVERTER", "Inverter"),
+    ("DeviceMode.VE_REG_MODE_OFF", "Off"),
+    ("DeviceMode.VE_REG_MODE_ECO", "Eco"),
+    // BluetoothStatus
+    ("BluetoothStatus.Off", "Off"),
+    ("BluetoothStatus.On", "On"),
+    // BluetoothCapBle
+    ("BluetoothCapBle.None", "None"),
+    ("BluetoothCapBle.BLE_Supports_Switching_Off", "Supports switching off"),
+    ("BluetoothCapBle.BLE_Switching_Off_Is_Permanent", "Switching off is permanent"),
+    // Load
+    ("Load.Off", "Off"),
+    ("Load.On", "On"),
+    // Alarm
+    ("Alarm.Off", "Off"),
+    ("Alarm.On", "On"),
+    // Relay
+    ("Relay.Off", "Off"),
+    ("Relay.On", "On"),
+    // TrackerMode
+    ("TrackerMode.Off", "Off"),
+    ("TrackerMode.VoltageOrCurrentLimited", "Voltage or current limited"),
+    ("TrackerMode.MppTrackerActive", "MPP tracker active"),
+    // MonitorMode
+    ("MonitorMode.SolarCharger", "Solar charger"),
+    ("MonitorMode.WindCharger", "Wind charger"),
+    ("MonitorMode.ShaftGenerator", "Shaft generator"),
+    ("MonitorMode.Alternator", "Alternator"),
+    ("MonitorMode.FuelCell", "Fuel cell"),
+    ("MonitorMode.WaterGenerator", "Water generator"),
+    ("MonitorMode.DcDcCharger", "DC/DC charger"),
+    ("MonitorMode.AcCharger", "AC charger"),
+    ("MonitorMode.GenericSource", "Generic source"),
+    ("MonitorMode.BatteryMonitor", "Battery monitor"),
+    ("MonitorMode.GenericLoad", "Generic load"),
+    ("MonitorMode.ElectricDrive", "Electric drive"),
+    ("MonitorMode.Fridge", "Fridge"),
+    ("MonitorMode.WaterPump", "Water pump"),
+    ("MonitorMode.BilgePump", "Bilge pump"),
+    ("MonitorMode.DcSystem", "DC system"),
+    ("MonitorMode.Inverter", "Inverter"),
+    ("MonitorMode.WaterHeater", "Water heater"),
+    // ProductFamily
+    ("ProductFamily.BatteryMonitor", "Battery monitor"),
+    ("ProductFamily.SolarCharger", "Solar charger"),
+    ("ProductFamily.Inverter", "Inverter"),
+    ("ProductFamily.DcDcConverter", "DC-DC converter"),
+    ("ProductFamily.BatteryProtect", "BatteryProtect"),
+    ("ProductFamily.AcCharger", "AC charger"),
+];
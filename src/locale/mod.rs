@@ -0,0 +1,64 @@
+//! # Example
+//! ```
+//! let mut polish = Locale::from_json("pl", r#"{"label.V": "Napięcie akumulatora"}"#).unwrap();
+//! polish.insert("StateOfOperation.Bulk", "Ładowanie główne");
+//! println!("{}: {}", Labels::V.describe(&polish), converted.CS.unwrap().describe(&polish));
+//! ```
+
+pub mod english;
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::*;
+use crate::metadata::label_metadata;
+use crate::metadata::models::Unit;
+use crate::product::models::ProductFamily;
+
+impl Describe for Labels {
+    fn translation_key(&self) -> String {
+        format!("label.{}", self)
+    }
+}
+
+macro_rules! describe_enum {
+    ( $( $enum: ident ),* ) => {
+        $(
+            impl Describe for $enum {
+                fn translation_key(&self) -> String {
+                    format!("{}.{:?}", stringify!($enum), self)
+                }
+            }
+        )*
+    };
+}
+
+describe_enum!(
+    StateOfOperation,
+    ErrorCode,
+    OffReason,
+    AlarmReason,
+    WarningReason,
+    DeviceMode,
+    BluetoothStatus,
+    BluetoothCapBle,
+    Load,
+    TrackerMode,
+    MonitorMode,
+    Alarm,
+    Relay,
+    ProductFamily
+);
+
+impl Describe for Unit {
+    fn translation_key(&self) -> String {
+        format!("unit.{:?}", self)
+    }
+}
+
+/// localised unit of converted value of label
+pub fn describe_unit(label: &Labels, locale: &Locale) -> Option<String> {
+    label_metadata(*label).unit.map(|unit| unit.describe(locale))
+}
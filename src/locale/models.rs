@@ -0,0 +1,62 @@
+use crate::locale::english::ENGLISH;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Result};
+
+/// Translation table keyed by `label.<label>`, `unit.<unit>` and `<Enum>.<Variant>`,
+/// missing entries fall back to english
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Locale {
+    pub code: String,
+    entries: HashMap<String, String>,
+}
+
+impl Locale {
+    pub fn new(code: &str, entries: HashMap<String, String>) -> Self {
+        Locale {
+            code: code.to_string(),
+            entries,
+        }
+    }
+
+    /// built-in english locale
+    pub fn english() -> Self {
+        Locale::new("en", HashMap::new())
+    }
+
+    /// loads locale from flat json object of key to translation
+    pub fn from_json(code: &str, json: &str) -> Result<Self> {
+        let entries = serde_json::from_str(json).map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        Ok(Locale::new(code, entries))
+    }
+
+    pub fn insert(&mut self, key: &str, translation: &str) {
+        self.entries.insert(key.to_string(), translation.to_string());
+    }
+
+    /// translates key, falls back to english and then to the key itself
+    pub fn translate(&self, key: &str) -> String {
+        match self.entries.get(key) {
+            Some(translation) => translation.clone(),
+            None => english(key).unwrap_or(key).to_string(),
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::english()
+    }
+}
+
+pub(crate) fn english(key: &str) -> Option<&'static str> {
+    ENGLISH.iter().find(|(k, _)| *k == key).map(|(_, translation)| *translation)
+}
+
+pub trait Describe {
+    fn translation_key(&self) -> String;
+
+    /// human readable description in given locale
+    fn describe(&self, locale: &Locale) -> String {
+        locale.translate(&self.translation_key())
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+fn assert_translated<T: Describe>(values: impl Iterator<Item = T>) {
+    for value in values {
+        let key = value.translation_key();
+        assert!(english(&key).is_some(), "missing english translation for {}", key);
+    }
+}
+
+#[test]
+fn english_coverage_test() {
+    assert_translated(Labels::iter());
+    assert_translated(StateOfOperation::iter());
+    assert_translated(ErrorCode::iter());
+    assert_translated(OffReason::iter());
+    assert_translated(AlarmReason::iter());
+    assert_translated(WarningReason::iter());
+    assert_translated(DeviceMode::iter());
+    assert_translated(BluetoothStatus::iter());
+    assert_translated(BluetoothCapBle::iter());
+    assert_translated(Load::iter());
+    assert_translated(TrackerMode::iter());
+    assert_translated(MonitorMode::iter());
+    assert_translated(Alarm::iter());
+    assert_translated(Relay::iter());
+    assert_translated(ProductFamily::iter());
+    assert_translated(Unit::iter());
+}
+
+#[test]
+fn describe_english_test() {
+    let english = Locale::english();
+    assert_eq!(StateOfOperation::RepeatedAbsorption.describe(&english), "Repeated absorption");
+    assert_eq!(ErrorCode::InputVoltageTooHigh.describe(&english), "Input voltage too high (solar panel)");
+    assert_eq!(Labels::SER.describe(&english), "Serial number");
+    assert_eq!(Labels::V.describe(&english), "Main (battery) voltage");
+    assert_eq!(describe_unit(&Labels::V, &english).unwrap(), "V");
+    assert_eq!(describe_unit(&Labels::T, &english).unwrap(), "°C");
+    assert_eq!(describe_unit(&Labels::CS, &english), None);
+}
+
+#[test]
+fn describe_custom_locale_test() {
+    let json = r#"{"label.V": "Napięcie akumulatora", "unit.DegreeCelsius": "st. C", "StateOfOperation.Float": "Podtrzymanie"}"#;
+    let mut polish = Locale::from_json("pl", json).unwrap();
+    polish.insert("StateOfOperation.Bulk", "Ładowanie główne");
+    assert_eq!(polish.code, "pl");
+    assert_eq!(Labels::V.describe(&polish), "Napięcie akumulatora");
+    assert_eq!(StateOfOperation::Float.describe(&polish), "Podtrzymanie");
+    assert_eq!(StateOfOperation::Bulk.describe(&polish), "Ładowanie główne");
+    assert_eq!(describe_unit(&Labels::T, &polish).unwrap(), "st. C");
+    // falls back to english
+    assert_eq!(StateOfOperation::Absorption.describe(&polish), "Absorption");
+    assert_eq!(polish.translate("no.such.key"), "no.such.key");
+
+    let mut entries = HashMap::new();
+    entries.insert("Load.On".to_string(), "Ein".to_string());
+    assert_eq!(Load::On.describe(&Locale::new("de", entries)), "Ein");
+
+    Locale::from_json("pl", "[1, 2]").expect_err("");
+}
@@ -0,0 +1,149 @@
+//! # Example
+//! ```
+//! for meta in registry().iter().filter(|meta| meta.history) {
+//!     println!("{}: {} [{}]", meta.name, meta.description, meta.unit.map(|u| u.to_string()).unwrap_or_default());
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::Labels;
+use crate::locale::models::{Describe, Locale};
+use crate::product::models::ProductFamily;
+use crate::product::models::ProductFamily::*;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+const ALL: &[ProductFamily] = &[
+    BatteryMonitor,
+    SolarCharger,
+    Inverter,
+    DcDcConverter,
+    BatteryProtect,
+    AcCharger,
+];
+const NOT_BMV: &[ProductFamily] = &[SolarCharger, Inverter, DcDcConverter, BatteryProtect, AcCharger];
+const BMV: &[ProductFamily] = &[BatteryMonitor];
+const MPPT: &[ProductFamily] = &[SolarCharger];
+const INVERTER: &[ProductFamily] = &[Inverter];
+const DCDC: &[ProductFamily] = &[DcDcConverter];
+const CHARGER: &[ProductFamily] = &[AcCharger];
+
+/// metadata of label
+pub fn label_metadata(label: Labels) -> LabelMetadata {
+    use DataType::*;
+    use Unit::*;
+
+    let (raw_unit, unit, scale, data_type, families): (Option<&'static str>, _, _, _, &'static [ProductFamily]) = match label {
+        Labels::V => (Some("mV"), Some(Volt), Some(0.001), Number, ALL),
+        Labels::VS => (Some("mV"), Some(Volt), Some(0.001), Number, &[BatteryMonitor, AcCharger]),
+        Labels::V2 | Labels::V3 => (Some("mV"), Some(Volt), Some(0.001), Number, CHARGER),
+        Labels::VM => (Some("mV"), Some(Volt), Some(0.001), Number, BMV),
+        Labels::DM => (Some("‰"), Some(Ratio), Some(0.001), Number, BMV),
+        Labels::VPV => (Some("mV"), Some(Volt), Some(0.001), Number, MPPT),
+        Labels::PPV => (Some("W"), Some(Watt), Some(1.0), Number, MPPT),
+        Labels::I => (Some("mA"), Some(Ampere), Some(0.001), Number, &[BatteryMonitor, SolarCharger, AcCharger]),
+        Labels::I2 | Labels::I3 => (Some("mA"), Some(Ampere), Some(0.001), Number, CHARGER),
+        Labels::IL => (Some("mA"), Some(Ampere), Some(0.001), Number, MPPT),
+        Labels::LOAD => (None, None, None, Enum, MPPT),
+        Labels::T => (Some("°C"), Some(DegreeCelsius), Some(1.0), Number, &[BatteryMonitor, AcCharger]),
+        Labels::P => (Some("W"), Some(Watt), Some(1.0), Number, BMV),
+        Labels::CE => (Some("mAh"), Some(AmpereHour), Some(0.001), Number, BMV),
+        Labels::SOC => (Some("‰"), Some(Ratio), Some(0.001), Number, BMV),
+        Labels::TTG => (Some("min"), Some(Second), Some(60.0), Duration, BMV),
+        Labels::Alarm => (None, None, None, Enum, BMV),
+        Labels::Relay => (None, None, None, Enum, &[BatteryMonitor, SolarCharger]),
+        Labels::AR => (None, None, None, Flags, &[BatteryMonitor, Inverter, BatteryProtect]),
+        Labels::OR => (None, None, None, Flags, &[SolarCharger, Inverter, DcDcConverter, BatteryProtect]),
+        Labels::H1 | Labels::H2 | Labels::H3 | Labels::H6 => (Some("mAh"), Some(AmpereHour), Some(0.001), Number, BMV),
+        Labels::H4 | Labels::H5 | Labels::H10 | Labels::H11 | Labels::H12 | Labels::H13 | Labels::H14 => {
+            (None, None, Some(1.0), Number, BMV)
+        }
+        Labels::H7 | Labels::H8 | Labels::H15 | Labels::H16 => (Some("mV"), Some(Volt), Some(0.001), Number, BMV),
+        Labels::H9 => (Some("s"), Some(Second), Some(1.0), Duration, BMV),
+        Labels::H17 | Labels::H18 => (Some("0.01 kWh"), Some(WattHour), Some(10.0), Number, BMV),
+        Labels::H19 | Labels::H20 | Labels::H22 => (Some("0.01 kWh"), Some(WattHour), Some(10.0), Number, MPPT),
+        Labels::H21 | Labels::H23 => (Some("W"), Some(Watt), Some(1.0), Number, MPPT),
+        Labels::ERR => (None, None, None, Enum, &[SolarCharger, DcDcConverter, BatteryProtect, AcCharger]),
+        Labels::CS => (None, None, None, Enum, NOT_BMV),
+        Labels::BMV => (None, None, None, Text, BMV),
+        Labels::FW | Labels::FWE | Labels::PID => (None, None, None, Text, ALL),
+        Labels::SER => (None, None, None, Text, NOT_BMV),
+        Labels::HSDS => (None, None, Some(1.0), Number, MPPT),
+        Labels::MODE => (None, None, None, Enum, &[Inverter, DcDcConverter, BatteryProtect]),
+        Labels::MPPT => (None, None, None, Enum, MPPT),
+        Labels::MON => (None, None, None, Enum, BMV),
+        Labels::AC_OUT_V => (Some("0.01 V"), Some(Volt), Some(0.01), Number, INVERTER),
+        Labels::AC_OUT_I => (Some("0.1 A"), Some(Ampere), Some(0.1), Number, INVERTER),
+        Labels::AC_OUT_S => (Some("VA"), Some(VoltAmpere), Some(1.0), Number, INVERTER),
+        Labels::DC_IN_V => (Some("0.01 V"), Some(Volt), Some(0.01), Number, DCDC),
+        Labels::DC_IN_I => (Some("0.1 A"), Some(Ampere), Some(0.1), Number, DCDC),
+        Labels::DC_IN_P => (Some("W"), Some(Watt), Some(1.0), Number, DCDC),
+        Labels::WARN => (None, None, None, Flags, &[Inverter, BatteryProtect]),
+        Labels::BLE | Labels::CAP_BLE => (None, None, None, Enum, &[SolarCharger, DcDcConverter, AcCharger]),
+        Labels::Checksum | Labels::Calc_sum => (None, None, None, Checksum, ALL),
+        Labels::Unknown | Labels::Extensions => (None, None, None, Map, ALL),
+        Labels::Time => (Some("s"), Some(Second), Some(1.0), Timestamp, ALL),
+    };
+    let history = matches!(
+        label,
+        Labels::H1
+            | Labels::H2
+            | Labels::H3
+            | Labels::H4
+            | Labels::H5
+            | Labels::H6
+            | Labels::H7
+            | Labels::H8
+            | Labels::H9
+            | Labels::H10
+            | Labels::H11
+            | Labels::H12
+            | Labels::H13
+            | Labels::H14
+            | Labels::H15
+            | Labels::H16
+            | Labels::H17
+            | Labels::H18
+            | Labels::H19
+            | Labels::H20
+            | Labels::H21
+            | Labels::H22
+            | Labels::H23
+            | Labels::HSDS
+    );
+
+    LabelMetadata {
+        label,
+        name: label.to_string(),
+        description: label.describe(&Locale::english()),
+        raw_unit,
+        unit,
+        scale,
+        data_type,
+        families,
+        history,
+    }
+}
+
+/// metadata of label by name as sent by device (`V`, `SER#`)
+pub fn label_metadata_by_name(name: &str) -> Option<LabelMetadata> {
+    Labels::from_str(name).ok().map(label_metadata)
+}
+
+/// metadata of all labels known to the crate
+pub fn registry() -> Vec<LabelMetadata> {
+    Labels::iter().map(label_metadata).collect()
+}
+
+/// metadata of labels sent by given product family
+pub fn family_labels(family: ProductFamily) -> Vec<LabelMetadata> {
+    Labels::iter()
+        .map(label_metadata)
+        .filter(|meta| meta.families.contains(&family))
+        .collect()
+}
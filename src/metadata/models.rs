@@ -0,0 +1,53 @@
+use crate::converter::models::Labels;
+use crate::product::models::ProductFamily;
+use serde::Serialize;
+use strum_macros::{Display, EnumIter};
+
+/// Unit of converted value
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Display, EnumIter, Hash)]
+pub enum Unit {
+    #[strum(serialize = "V")]
+    Volt,
+    #[strum(serialize = "A")]
+    Ampere,
+    #[strum(serialize = "W")]
+    Watt,
+    #[strum(serialize = "VA")]
+    VoltAmpere,
+    #[strum(serialize = "Ah")]
+    AmpereHour,
+    #[strum(serialize = "Wh")]
+    WattHour,
+    #[strum(serialize = "°C")]
+    DegreeCelsius,
+    #[strum(serialize = "s")]
+    Second,
+    #[strum(serialize = "0..1")]
+    Ratio,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Display, Hash)]
+pub enum DataType {
+    Number,
+    Duration,
+    Enum,
+    Flags,
+    Text,
+    Checksum,
+    Timestamp,
+    Map,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub struct LabelMetadata {
+    #[serde(skip)]
+    pub label: Labels,
+    pub name: String,                   // label as sent by device
+    pub description: String,            // english description
+    pub raw_unit: Option<&'static str>, // unit sent by device
+    pub unit: Option<Unit>,             // unit after conversion
+    pub scale: Option<f64>,             // converted = raw * scale
+    pub data_type: DataType,
+    pub families: &'static [ProductFamily],
+    pub history: bool, // belongs to history block (H1..H23, HSDS)
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[test]
+fn label_metadata_test() {
+    let meta = label_metadata(Labels::V);
+    assert_eq!(meta.name, "V");
+    assert_eq!(meta.description, "Main (battery) voltage");
+    assert_eq!(meta.raw_unit.unwrap(), "mV");
+    assert_eq!(meta.unit.unwrap(), Unit::Volt);
+    assert_eq!(meta.scale.unwrap(), 0.001);
+    assert_eq!(meta.data_type, DataType::Number);
+    assert!(!meta.history);
+
+    let meta = label_metadata(Labels::H20);
+    assert_eq!(meta.unit.unwrap(), Unit::WattHour);
+    assert_eq!(meta.scale.unwrap(), 10.0);
+    assert_eq!(meta.families, [ProductFamily::SolarCharger]);
+    assert!(meta.history);
+
+    let meta = label_metadata(Labels::CS);
+    assert_eq!(meta.data_type, DataType::Enum);
+    assert_eq!(meta.unit, None);
+    assert_eq!(meta.scale, None);
+    assert!(!meta.families.contains(&ProductFamily::BatteryMonitor));
+}
+
+#[test]
+fn label_metadata_by_name_test() {
+    assert_eq!(label_metadata_by_name("SER#").unwrap().label, Labels::SER);
+    assert_eq!(label_metadata_by_name("TTG").unwrap().data_type, DataType::Duration);
+    assert_eq!(label_metadata_by_name("Bleble"), None);
+}
+
+#[test]
+fn registry_test() {
+    let registry = registry();
+    assert_eq!(registry.len(), Labels::iter().count());
+    assert_eq!(registry.iter().filter(|meta| meta.history).count(), 24);
+    for meta in registry.iter() {
+        assert!(!meta.families.is_empty(), "{} without families", meta.name);
+        assert_eq!(meta.unit.is_some(), meta.raw_unit.is_some(), "{} raw unit without unit", meta.name);
+        if meta.data_type == DataType::Number {
+            assert!(meta.scale.is_some(), "{} without scale", meta.name);
+        }
+    }
+
+    let json = serde_json::to_value(label_metadata(Labels::VPV)).unwrap();
+    assert_eq!(json["name"], "VPV");
+    assert_eq!(json["unit"], "Volt");
+}
+
+#[test]
+fn family_labels_test() {
+    let mppt = family_labels(ProductFamily::SolarCharger);
+    assert!(mppt.iter().any(|meta| meta.label == Labels::VPV));
+    assert!(mppt.iter().any(|meta| meta.label == Labels::H20));
+    assert!(!mppt.iter().any(|meta| meta.label == Labels::SOC));
+}
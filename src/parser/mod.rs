@@ -9,6 +9,7 @@
 pub mod models;
 
 #[cfg(test)]
+#[allow(clippy::unnecessary_to_owned)]
 mod tests;
 
 use crate::parser::models::*;
@@ -0,0 +1,108 @@
+//! # Example
+//! ```
+//! // PID field of converted packet
+//! if let Some(product) = product_info(&converted.PID.unwrap()) {
+//!     println!("{} ({})", product.model, product.family);
+//! }
+//! ```
+
+pub mod models;
+pub mod table;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use self::table::PRODUCTS;
+use crate::converter::models::VEDirectData;
+use std::io::{Error, ErrorKind, Result};
+
+/// parses product id as sent by device (`0xA053`, `0x203`) into number
+pub fn parse_pid(pid: &str) -> Result<u16> {
+    let without_prefix = pid.trim().trim_start_matches("0x").trim_start_matches("0X");
+    u16::from_str_radix(without_prefix, 16).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+}
+
+/// looks up product by its numeric id
+pub fn product_by_id(pid: u16) -> Option<&'static ProductInfo> {
+    PRODUCTS.iter().find(|product| product.pid == pid)
+}
+
+/// looks up product by product id as sent by device, None when id is malformed or not known
+pub fn product_info(pid: &str) -> Option<&'static ProductInfo> {
+    parse_pid(pid).ok().and_then(product_by_id)
+}
+
+fn version_digits(digits: &str) -> Result<(u8, u8)> {
+    if digits.len() < 3 || digits.len() > 4 || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(Error::new(ErrorKind::InvalidData, format!("invalid version digits {}", digits)));
+    }
+    let val = digits.parse::<u16>().map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+    Ok(((val / 100) as u8, (val % 100) as u8))
+}
+
+/// parses 16 bit FW field (`159`, `0307`), leading letter marks release candidate (`C208`)
+pub fn parse_fw(fw: &str) -> Result<FirmwareVersion> {
+    let fw = fw.trim();
+    let (stage, digits) = match fw.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => (ReleaseStage::Candidate(c.to_ascii_uppercase()), &fw[1..]),
+        _ => (ReleaseStage::Release, fw),
+    };
+    let (major, minor) = version_digits(digits)?;
+    Ok(FirmwareVersion { major, minor, stage })
+}
+
+/// parses 24 bit FWE field (`0208FF`), last byte FF marks release otherwise it is beta number
+pub fn parse_fwe(fwe: &str) -> Result<FirmwareVersion> {
+    let fwe = fwe.trim();
+    if fwe.len() != 6 || !fwe.is_ascii() {
+        return Err(Error::new(ErrorKind::InvalidData, format!("invalid FWE {}", fwe)));
+    }
+    let (major, minor) = version_digits(&fwe[..4])?;
+    let stage = match u8::from_str_radix(&fwe[4..], 16).map_err(|err| Error::new(ErrorKind::InvalidData, err))? {
+        0xFF => ReleaseStage::Release,
+        beta => ReleaseStage::Beta(beta),
+    };
+    Ok(FirmwareVersion { major, minor, stage })
+}
+
+/// firmware version of converted packet, FWE takes precedence over FW
+pub fn firmware_version(data: &VEDirectData) -> Option<FirmwareVersion> {
+    data.FWE
+        .as_deref()
+        .and_then(|fwe| parse_fwe(fwe).ok())
+        .or_else(|| data.FW.as_deref().and_then(|fw| parse_fw(fw).ok()))
+}
+
+/// parses and validates SER# field, two letter factory code followed by year, week and alphanumeric suffix
+pub fn parse_serial(serial: &str) -> Result<SerialNumber> {
+    let serial = serial.trim();
+    let invalid = || Error::new(ErrorKind::InvalidData, format!("invalid serial number {}", serial));
+    if serial.len() < 9 || !serial.is_ascii() {
+        return Err(invalid());
+    }
+    let (factory, rest) = serial.split_at(2);
+    let (date, suffix) = rest.split_at(4);
+    if !factory.bytes().all(|c| c.is_ascii_uppercase())
+        || !date.bytes().all(|c| c.is_ascii_digit())
+        || !suffix.bytes().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let year = date[..2].parse::<u16>().map_err(|_| invalid())?;
+    let week = date[2..].parse::<u8>().map_err(|_| invalid())?;
+    if !(1..=53).contains(&week) {
+        return Err(invalid());
+    }
+    Ok(SerialNumber {
+        factory: factory.to_string(),
+        year: 2000 + year,
+        week,
+        suffix: suffix.to_string(),
+    })
+}
+
+/// serial number of converted packet, None when missing or malformed
+pub fn serial_number(data: &VEDirectData) -> Option<SerialNumber> {
+    data.SER.as_deref().and_then(|serial| parse_serial(serial).ok())
+}
@@ -0,0 +1,188 @@
+use crate::converter::models::{A, V};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use strum_macros::{Display, EnumIter};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Display, EnumIter, Hash)]
+pub enum ProductFamily {
+    BatteryMonitor,
+    SolarCharger,
+    Inverter,
+    DcDcConverter,
+    BatteryProtect,
+    AcCharger,
+}
+
+/// Nominal ratings of the product, fields not applicable to the family are None
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Default)]
+pub struct NominalRatings {
+    pub battery_voltage: Option<V>, //  [V] Battery (or dc input) voltage
+    pub output_voltage: Option<V>,  //  [V] Dc output voltage of dc-dc converters
+    pub pv_voltage: Option<V>,      //  [V] Maximum panel voltage
+    pub ac_voltage: Option<V>,      //  [V] Nominal ac output voltage
+    pub current: Option<A>,         //  [A] Charge, output or shunt current
+    pub power: Option<f64>,         //  [VA] Continuous ac output power
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Serialize)]
+pub struct ProductInfo {
+    pub pid: u16,
+    pub model: &'static str,
+    pub family: ProductFamily,
+    pub ratings: NominalRatings,
+}
+
+const NONE: NominalRatings = NominalRatings {
+    battery_voltage: None,
+    output_voltage: None,
+    pv_voltage: None,
+    ac_voltage: None,
+    current: None,
+    power: None,
+};
+
+impl ProductInfo {
+    pub(crate) const fn monitor(pid: u16, model: &'static str) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::BatteryMonitor,
+            ratings: NONE,
+        }
+    }
+
+    pub(crate) const fn shunt(pid: u16, model: &'static str, current: A) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::BatteryMonitor,
+            ratings: NominalRatings {
+                current: Some(current),
+                ..NONE
+            },
+        }
+    }
+
+    pub(crate) const fn mppt(pid: u16, model: &'static str, pv_voltage: V, current: A) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::SolarCharger,
+            ratings: NominalRatings {
+                pv_voltage: Some(pv_voltage),
+                current: Some(current),
+                ..NONE
+            },
+        }
+    }
+
+    pub(crate) const fn inverter(pid: u16, model: &'static str, battery_voltage: V, power: f64, ac_voltage: V) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::Inverter,
+            ratings: NominalRatings {
+                battery_voltage: Some(battery_voltage),
+                ac_voltage: Some(ac_voltage),
+                power: Some(power),
+                ..NONE
+            },
+        }
+    }
+
+    pub(crate) const fn orion(pid: u16, model: &'static str, input: V, output: V, current: A) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::DcDcConverter,
+            ratings: NominalRatings {
+                battery_voltage: Some(input),
+                output_voltage: Some(output),
+                current: Some(current),
+                ..NONE
+            },
+        }
+    }
+
+    pub(crate) const fn battery_protect(pid: u16, model: &'static str, current: A) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::BatteryProtect,
+            ratings: NominalRatings {
+                current: Some(current),
+                ..NONE
+            },
+        }
+    }
+
+    pub(crate) const fn charger(pid: u16, model: &'static str, battery_voltage: V, current: A) -> Self {
+        ProductInfo {
+            pid,
+            model,
+            family: ProductFamily::AcCharger,
+            ratings: NominalRatings {
+                battery_voltage: Some(battery_voltage),
+                current: Some(current),
+                ..NONE
+            },
+        }
+    }
+}
+
+/// Pre-release marker of firmware, ordered Beta < Candidate < Release
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Serialize, Hash)]
+pub enum ReleaseStage {
+    Beta(u8),
+    Candidate(char),
+    Release,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Serialize, Hash)]
+pub struct FirmwareVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub stage: ReleaseStage,
+}
+
+impl fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}.{:02}", self.major, self.minor)?;
+        match self.stage {
+            ReleaseStage::Beta(beta) => write!(f, "-beta.{}", beta),
+            ReleaseStage::Candidate(candidate) => write!(f, "-rc.{}", candidate),
+            ReleaseStage::Release => Ok(()),
+        }
+    }
+}
+
+/// Victron serial number (`HQ2132QY2KR`): factory code, production year and week, unique suffix.
+/// Serialised as the original string so it can be used as device identity key.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SerialNumber {
+    pub factory: String,
+    pub year: u16,
+    pub week: u8,
+    pub suffix: String,
+}
+
+impl fmt::Display for SerialNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:02}{:02}{}", self.factory, self.year % 100, self.week, self.suffix)
+    }
+}
+
+impl From<SerialNumber> for String {
+    fn from(serial: SerialNumber) -> Self {
+        serial.to_string()
+    }
+}
+
+impl TryFrom<String> for SerialNumber {
+    type Error = std::io::Error;
+
+    fn try_from(serial: String) -> std::io::Result<Self> {
+        crate::product::parse_serial(&serial)
+    }
+}
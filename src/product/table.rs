@@ -0,0 +1,147 @@
+use crate::product::models::ProductInfo;
+
+/// Product ids published in the VE.Direct protocol documentation
+pub static PRODUCTS: &[ProductInfo] = &[
+    // battery monitors
+    ProductInfo::monitor(0x0203, "BMV-700"),
+    ProductInfo::monitor(0x0204, "BMV-702"),
+    ProductInfo::monitor(0x0205, "BMV-700H"),
+    ProductInfo::monitor(0xA381, "BMV-712 Smart"),
+    ProductInfo::monitor(0xA382, "BMV-710H Smart"),
+    ProductInfo::monitor(0xA383, "BMV-712 Smart Rev2"),
+    ProductInfo::shunt(0xA389, "SmartShunt 500A/50mV", 500.0),
+    ProductInfo::shunt(0xA38A, "SmartShunt 1000A/50mV", 1000.0),
+    ProductInfo::shunt(0xA38B, "SmartShunt 2000A/50mV", 2000.0),
+    // solar chargers
+    ProductInfo::mppt(0x0300, "BlueSolar MPPT 70/15", 70.0, 15.0),
+    ProductInfo::mppt(0xA040, "BlueSolar MPPT 75/50", 75.0, 50.0),
+    ProductInfo::mppt(0xA041, "BlueSolar MPPT 150/35", 150.0, 35.0),
+    ProductInfo::mppt(0xA042, "BlueSolar MPPT 75/15", 75.0, 15.0),
+    ProductInfo::mppt(0xA043, "BlueSolar MPPT 100/15", 100.0, 15.0),
+    ProductInfo::mppt(0xA044, "BlueSolar MPPT 100/30", 100.0, 30.0),
+    ProductInfo::mppt(0xA045, "BlueSolar MPPT 100/50", 100.0, 50.0),
+    ProductInfo::mppt(0xA046, "BlueSolar MPPT 150/70", 150.0, 70.0),
+    ProductInfo::mppt(0xA047, "BlueSolar MPPT 150/100", 150.0, 100.0),
+    ProductInfo::mppt(0xA049, "BlueSolar MPPT 100/50 rev2", 100.0, 50.0),
+    ProductInfo::mppt(0xA04A, "BlueSolar MPPT 100/30 rev2", 100.0, 30.0),
+    ProductInfo::mppt(0xA04B, "BlueSolar MPPT 150/35 rev2", 150.0, 35.0),
+    ProductInfo::mppt(0xA04C, "BlueSolar MPPT 75/10", 75.0, 10.0),
+    ProductInfo::mppt(0xA04D, "BlueSolar MPPT 150/45", 150.0, 45.0),
+    ProductInfo::mppt(0xA04E, "BlueSolar MPPT 150/60", 150.0, 60.0),
+    ProductInfo::mppt(0xA04F, "BlueSolar MPPT 150/85", 150.0, 85.0),
+    ProductInfo::mppt(0xA050, "SmartSolar MPPT 250/100", 250.0, 100.0),
+    ProductInfo::mppt(0xA051, "SmartSolar MPPT 150/100", 150.0, 100.0),
+    ProductInfo::mppt(0xA052, "SmartSolar MPPT 150/85", 150.0, 85.0),
+    ProductInfo::mppt(0xA053, "SmartSolar MPPT 75/15", 75.0, 15.0),
+    ProductInfo::mppt(0xA054, "SmartSolar MPPT 75/10", 75.0, 10.0),
+    ProductInfo::mppt(0xA055, "SmartSolar MPPT 100/15", 100.0, 15.0),
+    ProductInfo::mppt(0xA056, "SmartSolar MPPT 100/30", 100.0, 30.0),
+    ProductInfo::mppt(0xA057, "SmartSolar MPPT 100/50", 100.0, 50.0),
+    ProductInfo::mppt(0xA058, "SmartSolar MPPT 150/35", 150.0, 35.0),
+    ProductInfo::mppt(0xA059, "SmartSolar MPPT 150/100 rev2", 150.0, 100.0),
+    ProductInfo::mppt(0xA05A, "SmartSolar MPPT 150/85 rev2", 150.0, 85.0),
+    ProductInfo::mppt(0xA05B, "SmartSolar MPPT 250/70", 250.0, 70.0),
+    ProductInfo::mppt(0xA05C, "SmartSolar MPPT 250/85", 250.0, 85.0),
+    ProductInfo::mppt(0xA05D, "SmartSolar MPPT 250/60", 250.0, 60.0),
+    ProductInfo::mppt(0xA05E, "SmartSolar MPPT 250/45", 250.0, 45.0),
+    ProductInfo::mppt(0xA05F, "SmartSolar MPPT 100/20", 100.0, 20.0),
+    ProductInfo::mppt(0xA060, "SmartSolar MPPT 100/20 48V", 100.0, 20.0),
+    ProductInfo::mppt(0xA061, "SmartSolar MPPT 150/45", 150.0, 45.0),
+    ProductInfo::mppt(0xA062, "SmartSolar MPPT 150/60", 150.0, 60.0),
+    ProductInfo::mppt(0xA063, "SmartSolar MPPT 150/70", 150.0, 70.0),
+    ProductInfo::mppt(0xA064, "SmartSolar MPPT 250/85 rev2", 250.0, 85.0),
+    ProductInfo::mppt(0xA065, "SmartSolar MPPT 250/100 rev2", 250.0, 100.0),
+    ProductInfo::mppt(0xA066, "BlueSolar MPPT 100/20", 100.0, 20.0),
+    ProductInfo::mppt(0xA067, "BlueSolar MPPT 100/20 48V", 100.0, 20.0),
+    ProductInfo::mppt(0xA068, "SmartSolar MPPT 250/60 rev2", 250.0, 60.0),
+    ProductInfo::mppt(0xA069, "SmartSolar MPPT 250/70 rev2", 250.0, 70.0),
+    ProductInfo::mppt(0xA06A, "SmartSolar MPPT 150/45 rev2", 150.0, 45.0),
+    ProductInfo::mppt(0xA06B, "SmartSolar MPPT 150/60 rev2", 150.0, 60.0),
+    ProductInfo::mppt(0xA06C, "SmartSolar MPPT 150/70 rev2", 150.0, 70.0),
+    ProductInfo::mppt(0xA06D, "SmartSolar MPPT 150/85 rev3", 150.0, 85.0),
+    ProductInfo::mppt(0xA06E, "SmartSolar MPPT 150/100 rev3", 150.0, 100.0),
+    ProductInfo::mppt(0xA06F, "BlueSolar MPPT 150/45 rev2", 150.0, 45.0),
+    ProductInfo::mppt(0xA070, "BlueSolar MPPT 150/60 rev2", 150.0, 60.0),
+    ProductInfo::mppt(0xA071, "BlueSolar MPPT 150/70 rev2", 150.0, 70.0),
+    ProductInfo::mppt(0xA102, "SmartSolar MPPT VE.Can 150/70", 150.0, 70.0),
+    ProductInfo::mppt(0xA103, "SmartSolar MPPT VE.Can 150/45", 150.0, 45.0),
+    ProductInfo::mppt(0xA104, "SmartSolar MPPT VE.Can 150/60", 150.0, 60.0),
+    ProductInfo::mppt(0xA105, "SmartSolar MPPT VE.Can 150/85", 150.0, 85.0),
+    ProductInfo::mppt(0xA106, "SmartSolar MPPT VE.Can 150/100", 150.0, 100.0),
+    ProductInfo::mppt(0xA107, "SmartSolar MPPT VE.Can 250/45", 250.0, 45.0),
+    ProductInfo::mppt(0xA108, "SmartSolar MPPT VE.Can 250/60", 250.0, 60.0),
+    ProductInfo::mppt(0xA109, "SmartSolar MPPT VE.Can 250/70", 250.0, 70.0),
+    ProductInfo::mppt(0xA10A, "SmartSolar MPPT VE.Can 250/85", 250.0, 85.0),
+    ProductInfo::mppt(0xA10B, "SmartSolar MPPT VE.Can 250/100", 250.0, 100.0),
+    ProductInfo::mppt(0xA10C, "SmartSolar MPPT VE.Can 150/70 rev2", 150.0, 70.0),
+    ProductInfo::mppt(0xA10D, "SmartSolar MPPT VE.Can 150/85 rev2", 150.0, 85.0),
+    ProductInfo::mppt(0xA10E, "SmartSolar MPPT VE.Can 150/100 rev2", 150.0, 100.0),
+    ProductInfo::mppt(0xA10F, "BlueSolar MPPT VE.Can 150/100", 150.0, 100.0),
+    ProductInfo::mppt(0xA112, "BlueSolar MPPT VE.Can 250/70", 250.0, 70.0),
+    ProductInfo::mppt(0xA113, "BlueSolar MPPT VE.Can 250/100", 250.0, 100.0),
+    ProductInfo::mppt(0xA114, "SmartSolar MPPT VE.Can 250/70 rev2", 250.0, 70.0),
+    ProductInfo::mppt(0xA115, "SmartSolar MPPT VE.Can 250/100 rev2", 250.0, 100.0),
+    ProductInfo::mppt(0xA116, "SmartSolar MPPT VE.Can 250/85 rev2", 250.0, 85.0),
+    // inverters
+    ProductInfo::inverter(0xA231, "Phoenix Inverter 12V 250VA 230V", 12.0, 250.0, 230.0),
+    ProductInfo::inverter(0xA232, "Phoenix Inverter 24V 250VA 230V", 24.0, 250.0, 230.0),
+    ProductInfo::inverter(0xA234, "Phoenix Inverter 48V 250VA 230V", 48.0, 250.0, 230.0),
+    ProductInfo::inverter(0xA239, "Phoenix Inverter 12V 250VA 120V", 12.0, 250.0, 120.0),
+    ProductInfo::inverter(0xA23A, "Phoenix Inverter 24V 250VA 120V", 24.0, 250.0, 120.0),
+    ProductInfo::inverter(0xA23C, "Phoenix Inverter 48V 250VA 120V", 48.0, 250.0, 120.0),
+    ProductInfo::inverter(0xA241, "Phoenix Inverter 12V 375VA 230V", 12.0, 375.0, 230.0),
+    ProductInfo::inverter(0xA242, "Phoenix Inverter 24V 375VA 230V", 24.0, 375.0, 230.0),
+    ProductInfo::inverter(0xA244, "Phoenix Inverter 48V 375VA 230V", 48.0, 375.0, 230.0),
+    ProductInfo::inverter(0xA249, "Phoenix Inverter 12V 375VA 120V", 12.0, 375.0, 120.0),
+    ProductInfo::inverter(0xA24A, "Phoenix Inverter 24V 375VA 120V", 24.0, 375.0, 120.0),
+    ProductInfo::inverter(0xA24C, "Phoenix Inverter 48V 375VA 120V", 48.0, 375.0, 120.0),
+    ProductInfo::inverter(0xA251, "Phoenix Inverter 12V 500VA 230V", 12.0, 500.0, 230.0),
+    ProductInfo::inverter(0xA252, "Phoenix Inverter 24V 500VA 230V", 24.0, 500.0, 230.0),
+    ProductInfo::inverter(0xA254, "Phoenix Inverter 48V 500VA 230V", 48.0, 500.0, 230.0),
+    ProductInfo::inverter(0xA259, "Phoenix Inverter 12V 500VA 120V", 12.0, 500.0, 120.0),
+    ProductInfo::inverter(0xA25A, "Phoenix Inverter 24V 500VA 120V", 24.0, 500.0, 120.0),
+    ProductInfo::inverter(0xA25C, "Phoenix Inverter 48V 500VA 120V", 48.0, 500.0, 120.0),
+    ProductInfo::inverter(0xA261, "Phoenix Inverter 12V 800VA 230V", 12.0, 800.0, 230.0),
+    ProductInfo::inverter(0xA262, "Phoenix Inverter 24V 800VA 230V", 24.0, 800.0, 230.0),
+    ProductInfo::inverter(0xA264, "Phoenix Inverter 48V 800VA 230V", 48.0, 800.0, 230.0),
+    ProductInfo::inverter(0xA269, "Phoenix Inverter 12V 800VA 120V", 12.0, 800.0, 120.0),
+    ProductInfo::inverter(0xA26A, "Phoenix Inverter 24V 800VA 120V", 24.0, 800.0, 120.0),
+    ProductInfo::inverter(0xA26C, "Phoenix Inverter 48V 800VA 120V", 48.0, 800.0, 120.0),
+    ProductInfo::inverter(0xA271, "Phoenix Inverter 12V 1200VA 230V", 12.0, 1200.0, 230.0),
+    ProductInfo::inverter(0xA272, "Phoenix Inverter 24V 1200VA 230V", 24.0, 1200.0, 230.0),
+    ProductInfo::inverter(0xA274, "Phoenix Inverter 48V 1200VA 230V", 48.0, 1200.0, 230.0),
+    ProductInfo::inverter(0xA279, "Phoenix Inverter 12V 1200VA 120V", 12.0, 1200.0, 120.0),
+    ProductInfo::inverter(0xA27A, "Phoenix Inverter 24V 1200VA 120V", 24.0, 1200.0, 120.0),
+    ProductInfo::inverter(0xA27C, "Phoenix Inverter 48V 1200VA 120V", 48.0, 1200.0, 120.0),
+    // ac chargers
+    ProductInfo::charger(0xA340, "Phoenix Smart IP43 Charger 12|50 (1+1)", 12.0, 50.0),
+    ProductInfo::charger(0xA341, "Phoenix Smart IP43 Charger 12|50 (3)", 12.0, 50.0),
+    ProductInfo::charger(0xA342, "Phoenix Smart IP43 Charger 24|25 (1+1)", 24.0, 25.0),
+    ProductInfo::charger(0xA343, "Phoenix Smart IP43 Charger 24|25 (3)", 24.0, 25.0),
+    ProductInfo::charger(0xA344, "Phoenix Smart IP43 Charger 12|30 (1+1)", 12.0, 30.0),
+    ProductInfo::charger(0xA345, "Phoenix Smart IP43 Charger 12|30 (3)", 12.0, 30.0),
+    ProductInfo::charger(0xA346, "Phoenix Smart IP43 Charger 24|16 (1+1)", 24.0, 16.0),
+    ProductInfo::charger(0xA347, "Phoenix Smart IP43 Charger 24|16 (3)", 24.0, 16.0),
+    // dc-dc converters
+    ProductInfo::orion(0xA3C0, "Orion Smart 12V|12V-18A Isolated DC-DC Charger", 12.0, 12.0, 18.0),
+    ProductInfo::orion(0xA3C1, "Orion Smart 12V|24V-10A Isolated DC-DC Charger", 12.0, 24.0, 10.0),
+    ProductInfo::orion(0xA3C3, "Orion Smart 24V|12V-20A Isolated DC-DC Charger", 24.0, 12.0, 20.0),
+    ProductInfo::orion(0xA3C4, "Orion Smart 24V|24V-12A Isolated DC-DC Charger", 24.0, 24.0, 12.0),
+    ProductInfo::orion(0xA3C6, "Orion Smart 48V|12V-20A Isolated DC-DC Charger", 48.0, 12.0, 20.0),
+    ProductInfo::orion(0xA3C7, "Orion Smart 48V|24V-12A Isolated DC-DC Charger", 48.0, 24.0, 12.0),
+    ProductInfo::orion(0xA3C8, "Orion Smart 12V|12V-30A Isolated DC-DC Charger", 12.0, 12.0, 30.0),
+    ProductInfo::orion(0xA3C9, "Orion Smart 12V|24V-15A Isolated DC-DC Charger", 12.0, 24.0, 15.0),
+    ProductInfo::orion(0xA3CA, "Orion Smart 24V|12V-30A Isolated DC-DC Charger", 24.0, 12.0, 30.0),
+    ProductInfo::orion(0xA3CB, "Orion Smart 24V|24V-17A Isolated DC-DC Charger", 24.0, 24.0, 17.0),
+    ProductInfo::orion(0xA3CC, "Orion Smart 48V|12V-30A Isolated DC-DC Charger", 48.0, 12.0, 30.0),
+    ProductInfo::orion(0xA3CD, "Orion Smart 48V|24V-16A Isolated DC-DC Charger", 48.0, 24.0, 16.0),
+    ProductInfo::orion(0xA3D0, "Orion Smart 12V|12V-30A Non-isolated DC-DC Charger", 12.0, 12.0, 30.0),
+    ProductInfo::orion(0xA3D1, "Orion Smart 12V|24V-15A Non-isolated DC-DC Charger", 12.0, 24.0, 15.0),
+    ProductInfo::orion(0xA3D2, "Orion Smart 24V|12V-30A Non-isolated DC-DC Charger", 24.0, 12.0, 30.0),
+    ProductInfo::orion(0xA3D3, "Orion Smart 24V|24V-17A Non-isolated DC-DC Charger", 24.0, 24.0, 17.0),
+    // battery protects
+    ProductInfo::battery_protect(0xA3E0, "Smart BatteryProtect 12/24V-65A", 65.0),
+    ProductInfo::battery_protect(0xA3E1, "Smart BatteryProtect 12/24V-100A", 100.0),
+    ProductInfo::battery_protect(0xA3E2, "Smart BatteryProtect 12/24V-220A", 220.0),
+    ProductInfo::battery_protect(0xA3E4, "Smart BatteryProtect 48V-100A", 100.0),
+];
@@ -0,0 +1,151 @@
+use super::*;
+use crate::converter::convert;
+use crate::product::models::{FirmwareVersion, ProductFamily, ReleaseStage, SerialNumber};
+use std::collections::HashMap;
+
+#[test]
+fn parse_pid_test() {
+    assert_eq!(parse_pid("0xA053").unwrap(), 0xA053);
+    assert_eq!(parse_pid("0x203").unwrap(), 0x0203);
+    assert_eq!(parse_pid("A381").unwrap(), 0xA381);
+    parse_pid("0xZZZZ").expect_err("");
+    parse_pid("0x1A0530").expect_err("");
+}
+
+#[test]
+fn product_info_test() {
+    let product = product_info("0xA056").unwrap();
+    assert_eq!(product.model, "SmartSolar MPPT 100/30");
+    assert_eq!(product.family, ProductFamily::SolarCharger);
+    assert_eq!(product.ratings.pv_voltage.unwrap(), 100.0);
+    assert_eq!(product.ratings.current.unwrap(), 30.0);
+
+    let product = product_info("0x203").unwrap();
+    assert_eq!(product.model, "BMV-700");
+    assert_eq!(product.family, ProductFamily::BatteryMonitor);
+    assert_eq!(product.ratings.current, None);
+
+    let product = product_info("0xA389").unwrap();
+    assert_eq!(product.family, ProductFamily::BatteryMonitor);
+    assert_eq!(product.ratings.current.unwrap(), 500.0);
+
+    let product = product_info("0xA254").unwrap();
+    assert_eq!(product.family, ProductFamily::Inverter);
+    assert_eq!(product.ratings.battery_voltage.unwrap(), 48.0);
+    assert_eq!(product.ratings.power.unwrap(), 500.0);
+    assert_eq!(product.ratings.ac_voltage.unwrap(), 230.0);
+
+    assert_eq!(product_info("0xA3C1").unwrap().family, ProductFamily::DcDcConverter);
+    assert_eq!(product_info("0xA340").unwrap().family, ProductFamily::AcCharger);
+    assert_eq!(product_info("0xA3E0").unwrap().family, ProductFamily::BatteryProtect);
+
+    assert_eq!(product_info("0xFFFF"), None);
+    assert_eq!(product_info("STRING"), None);
+}
+
+#[test]
+fn unique_pids_test() {
+    for (i, product) in PRODUCTS.iter().enumerate() {
+        assert!(PRODUCTS[i + 1..].iter().all(|other| other.pid != product.pid), "duplicate pid {:#06x}", product.pid);
+    }
+}
+
+#[test]
+fn parse_fw_test() {
+    let fw = parse_fw("159").unwrap();
+    assert_eq!(
+        fw,
+        FirmwareVersion {
+            major: 1,
+            minor: 59,
+            stage: ReleaseStage::Release
+        }
+    );
+    assert_eq!(fw.to_string(), "v1.59");
+    assert_eq!(parse_fw("0307").unwrap().to_string(), "v3.07");
+    assert_eq!(parse_fw("1102").unwrap().to_string(), "v11.02");
+    let rc = parse_fw("C208").unwrap();
+    assert_eq!(rc.stage, ReleaseStage::Candidate('C'));
+    assert_eq!(rc.to_string(), "v2.08-rc.C");
+    parse_fw("12").expect_err("");
+    parse_fw("STRING").expect_err("");
+    parse_fw("").expect_err("");
+}
+
+#[test]
+fn parse_fwe_test() {
+    let fwe = parse_fwe("0208FF").unwrap();
+    assert_eq!(
+        fwe,
+        FirmwareVersion {
+            major: 2,
+            minor: 8,
+            stage: ReleaseStage::Release
+        }
+    );
+    assert_eq!(fwe.to_string(), "v2.08");
+    let beta = parse_fwe("015903").unwrap();
+    assert_eq!(beta.stage, ReleaseStage::Beta(3));
+    assert_eq!(beta.to_string(), "v1.59-beta.3");
+    parse_fwe("0208").expect_err("");
+    parse_fwe("02A8FF").expect_err("");
+    parse_fwe("0208GG").expect_err("");
+}
+
+#[test]
+fn firmware_ordering_test() {
+    let beta = parse_fwe("020801").unwrap();
+    let rc = parse_fw("C208").unwrap();
+    let release = parse_fw("208").unwrap();
+    assert!(beta < rc);
+    assert!(rc < release);
+    assert!(release < parse_fw("209").unwrap());
+    assert!(parse_fw("0307").unwrap() > parse_fw("159").unwrap());
+    assert!(parse_fw("1000").unwrap() > parse_fw("999").unwrap());
+}
+
+#[test]
+fn firmware_version_test() {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("FW".to_string(), "159".as_bytes().to_vec());
+    assert_eq!(firmware_version(&convert(map.clone()).unwrap()).unwrap().to_string(), "v1.59");
+
+    map.insert("FWE".to_string(), "016002".as_bytes().to_vec());
+    assert_eq!(firmware_version(&convert(map).unwrap()).unwrap().to_string(), "v1.60-beta.2");
+
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("FW".to_string(), "STRING".as_bytes().to_vec());
+    assert_eq!(firmware_version(&convert(map).unwrap()), None);
+}
+
+#[test]
+fn parse_serial_test() {
+    let serial = parse_serial("HQ2132QY2KR").unwrap();
+    assert_eq!(serial.factory, "HQ");
+    assert_eq!(serial.year, 2021);
+    assert_eq!(serial.week, 32);
+    assert_eq!(serial.suffix, "QY2KR");
+    assert_eq!(serial.to_string(), "HQ2132QY2KR");
+    assert_eq!(parse_serial("HQ1905ABC").unwrap().week, 5);
+
+    parse_serial("HQ2100QY2KR").expect_err("");
+    parse_serial("HQ2154QY2KR").expect_err("");
+    parse_serial("hq2132QY2KR").expect_err("");
+    parse_serial("HQ21A2QY2KR").expect_err("");
+    parse_serial("HQ2132QY-KR").expect_err("");
+    parse_serial("HQ2132").expect_err("");
+    parse_serial("STRING").expect_err("");
+}
+
+#[test]
+fn serial_identity_test() {
+    let serial = parse_serial("HQ2132QY2KR").unwrap();
+    let json = serde_json::to_string(&serial).unwrap();
+    assert_eq!(json, "\"HQ2132QY2KR\"");
+    assert_eq!(serde_json::from_str::<SerialNumber>(&json).unwrap(), serial);
+    serde_json::from_str::<SerialNumber>("\"STRING\"").expect_err("");
+
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    map.insert("SER#".to_string(), "HQ2132QY2KR".as_bytes().to_vec());
+    assert_eq!(serial_number(&convert(map).unwrap()).unwrap(), serial);
+}
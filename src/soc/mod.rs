@@ -0,0 +1,181 @@
+//! # Example
+//! ```
+//! let mut estimator = SocEstimator::new(SocConfig::new(Chemistry::Agm));
+//! // converted variable from converter output of solar charger, Time is set by parser
+//! if let Some(estimate) = estimator.update(&converted) {
+//!     println!("SOC {:.0}% ({:?})", estimate.soc * 100.0, estimate.confidence);
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Ratio, StateOfOperation, VEDirectData, A, V};
+use crate::device::detect_family;
+use crate::health::models::Chemistry;
+use crate::product::models::ProductFamily;
+
+/// open circuit voltage of 12 V battery against state of charge, ascending
+pub fn ocv_curve(chemistry: Chemistry) -> &'static [(V, Ratio)] {
+    match chemistry {
+        Chemistry::FloodedLeadAcid => &[(11.8, 0.0), (12.0, 0.25), (12.2, 0.5), (12.4, 0.75), (12.7, 1.0)],
+        Chemistry::Agm => &[(11.8, 0.0), (12.0, 0.25), (12.3, 0.5), (12.6, 0.75), (12.85, 1.0)],
+        Chemistry::Gel => &[(11.8, 0.0), (12.1, 0.25), (12.35, 0.5), (12.65, 0.75), (12.85, 1.0)],
+        Chemistry::LiFePO4 => &[
+            (12.0, 0.0),
+            (12.8, 0.1),
+            (12.9, 0.2),
+            (13.0, 0.3),
+            (13.1, 0.4),
+            (13.2, 0.7),
+            (13.3, 0.9),
+            (13.6, 1.0),
+        ],
+    }
+}
+
+/// state of charge of 12 V battery at open circuit voltage, linear between points of curve
+pub fn soc_from_voltage(chemistry: Chemistry, voltage: V) -> Ratio {
+    let curve = ocv_curve(chemistry);
+    let (first, last) = (curve[0], curve[curve.len() - 1]);
+    if voltage <= first.0 {
+        return first.1;
+    }
+    if voltage >= last.0 {
+        return last.1;
+    }
+    for pair in curve.windows(2) {
+        let ((v0, soc0), (v1, soc1)) = (pair[0], pair[1]);
+        if voltage <= v1 {
+            return soc0 + (soc1 - soc0) * (voltage - v0) / (v1 - v0);
+        }
+    }
+    last.1
+}
+
+/// nominal voltage of battery bank guessed from its voltage
+pub fn detect_nominal_voltage(voltage: V) -> V {
+    if voltage < 18_f64 {
+        12_f64
+    } else if voltage < 36_f64 {
+        24_f64
+    } else {
+        48_f64
+    }
+}
+
+/// Estimates state of charge from voltage, current and charger state of systems without battery monitor.
+/// Current of a charger does not include loads connected to the battery directly, so the battery may
+/// be discharging while it seems to rest; estimates from such devices are at most of medium confidence.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SocEstimator {
+    config: SocConfig,
+    rest_since: Option<i64>,
+    last_time: Option<i64>,
+    anchor_time: Option<i64>, // time of last float or rest voltage estimate
+    estimate: Option<SocEstimate>,
+}
+
+impl SocEstimator {
+    pub fn new(config: SocConfig) -> Self {
+        Self {
+            config,
+            rest_since: None,
+            last_time: None,
+            anchor_time: None,
+            estimate: None,
+        }
+    }
+
+    /// last estimate
+    pub fn estimate(&self) -> Option<&SocEstimate> {
+        self.estimate.as_ref()
+    }
+
+    /// updates estimate with frame, frames without V keep previous estimate
+    pub fn update(&mut self, data: &VEDirectData) -> Option<&SocEstimate> {
+        let voltage = match data.V {
+            Some(voltage) => voltage,
+            None => return self.estimate.as_ref(),
+        };
+        let scale = 12_f64 / self.config.nominal_voltage.unwrap_or_else(|| detect_nominal_voltage(voltage));
+        let chemistry = self.config.chemistry;
+        // battery current of chargers already has their load output (IL) subtracted
+        let current = data.I;
+        let max_confidence = if detect_family(data) == Some(ProductFamily::BatteryMonitor) {
+            Confidence::High
+        } else {
+            Confidence::Medium
+        };
+
+        let rested = self.rested(current, data.Time);
+        let previous = self.estimate.take();
+        let elapsed_hours = match (self.last_time, data.Time) {
+            (Some(last), Some(time)) if time > last && time - last <= self.config.max_gap.as_secs() as i64 => {
+                Some((time - last) as f64 / 3600_f64)
+            }
+            _ => None,
+        };
+        self.last_time = data.Time.or(self.last_time);
+
+        let (soc, confidence, method) = if matches!(data.CS, Some(StateOfOperation::Float | StateOfOperation::Storage)) {
+            // charger itself reports battery is full
+            (1_f64, Confidence::High, SocMethod::Float)
+        } else if rested {
+            let soc = soc_from_voltage(chemistry, voltage * scale);
+            // flat middle of lithium curve hardly tells charge levels apart
+            let confidence = if chemistry == Chemistry::LiFePO4 && (0.2..0.9).contains(&soc) {
+                Confidence::Medium
+            } else {
+                Confidence::High
+            };
+            (soc, confidence.min(max_confidence), SocMethod::RestVoltage)
+        } else if let (Some(previous), Some(capacity), Some(current), Some(hours)) =
+            (previous.as_ref(), self.config.capacity, current, elapsed_hours)
+        {
+            let soc = (previous.soc + current * hours / capacity).clamp(0_f64, 1_f64);
+            // error of counting grows with time since last anchor
+            let counting = match (self.anchor_time, data.Time) {
+                (Some(anchor), Some(time)) => time - anchor,
+                _ => i64::MAX,
+            };
+            let confidence = if counting <= self.config.max_counting.as_secs() as i64 {
+                previous.confidence.min(Confidence::Medium).min(max_confidence)
+            } else {
+                Confidence::Low
+            };
+            (soc, confidence, SocMethod::CoulombCounting)
+        } else {
+            let compensated = voltage * scale - current.unwrap_or(0_f64) * self.config.internal_resistance;
+            (soc_from_voltage(chemistry, compensated), Confidence::Low, SocMethod::LoadedVoltage)
+        };
+        if matches!(method, SocMethod::Float | SocMethod::RestVoltage) {
+            self.anchor_time = data.Time;
+        }
+
+        self.estimate = Some(SocEstimate {
+            soc,
+            confidence,
+            method,
+            time: data.Time,
+        });
+        self.estimate.as_ref()
+    }
+
+    /// tracks since when current stays within rest current, unknown current is never rest
+    fn rested(&mut self, current: Option<A>, time: Option<i64>) -> bool {
+        match (current, time) {
+            (Some(current), Some(time)) if current.abs() <= self.config.rest_current => {
+                let since = *self.rest_since.get_or_insert(time);
+                time - since >= self.config.rest_time.as_secs() as i64
+            }
+            _ => {
+                self.rest_since = None;
+                false
+            }
+        }
+    }
+}
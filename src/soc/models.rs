@@ -0,0 +1,55 @@
+use crate::converter::models::{Ratio, A, V};
+use crate::health::models::Chemistry;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+/// How the estimate was obtained
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum SocMethod {
+    Float,           // charger in float or storage, battery is full
+    RestVoltage,     // open circuit voltage after battery rested
+    CoulombCounting, // current integrated since last rest or float estimate (anchor)
+    LoadedVoltage,   // voltage under load compensated by internal resistance
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct SocEstimate {
+    pub soc: Ratio, // [0..1]
+    pub confidence: Confidence,
+    pub method: SocMethod,
+    pub time: Option<i64>, // [s] unix timestamp of frame estimate was made from
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct SocConfig {
+    pub chemistry: Chemistry,
+    pub nominal_voltage: Option<V>, // [V] 12, 24 or 48, detected from battery voltage when None
+    pub capacity: Option<f64>,      // [Ah] enables coulomb counting between voltage estimates
+    pub rest_current: A,            // [A] battery is resting while current stays within this
+    pub rest_time: Duration,        // battery has to rest this long for open circuit voltage to settle
+    pub internal_resistance: f64,   // [Ω] of 12 V block, compensates voltage under load
+    pub max_gap: Duration,          // coulomb counting stops when frames are further apart
+    pub max_counting: Duration,     // coulomb counted estimate drops to low confidence this long after last anchor
+}
+
+impl SocConfig {
+    pub fn new(chemistry: Chemistry) -> Self {
+        Self {
+            chemistry,
+            nominal_voltage: None,
+            capacity: None,
+            rest_current: 0.5,
+            rest_time: Duration::from_secs(30 * 60),
+            internal_resistance: 0.02,
+            max_gap: Duration::from_secs(60),
+            max_counting: Duration::from_secs(24 * 3600),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::test_utils::{frame, round};
+use std::time::Duration;
+
+#[test]
+fn soc_from_voltage_test() {
+    assert_eq!(soc_from_voltage(Chemistry::FloodedLeadAcid, 11.0), 0.0);
+    assert_eq!(soc_from_voltage(Chemistry::FloodedLeadAcid, 12.2), 0.5);
+    assert_eq!(round(soc_from_voltage(Chemistry::FloodedLeadAcid, 12.3)), 0.625);
+    assert_eq!(soc_from_voltage(Chemistry::LiFePO4, 14.0), 1.0);
+    assert_eq!(detect_nominal_voltage(26.2), 24.0);
+}
+
+#[test]
+fn rest_test() {
+    let mut estimator = SocEstimator::new(SocConfig::new(Chemistry::FloodedLeadAcid));
+
+    // 24 V bank, open circuit voltage settles after rest time
+    let estimate = estimator
+        .update(&frame(0, &[("V", "24400"), ("I", "100"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!(estimate.method, SocMethod::LoadedVoltage);
+    assert_eq!(estimate.confidence, Confidence::Low);
+
+    let estimate = estimator
+        .update(&frame(1800, &[("V", "24400"), ("I", "100"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!(estimate.method, SocMethod::RestVoltage);
+    // charger does not see loads connected to battery directly
+    assert_eq!(estimate.confidence, Confidence::Medium);
+    assert_eq!(round(estimate.soc), 0.5);
+
+    // load ends rest
+    let estimate = estimator
+        .update(&frame(1801, &[("V", "24000"), ("I", "-10000"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!(estimate.method, SocMethod::LoadedVoltage);
+}
+
+#[test]
+fn float_and_coulomb_counting_test() {
+    let mut config = SocConfig::new(Chemistry::LiFePO4);
+    config.capacity = Some(100.0);
+    config.max_gap = Duration::from_secs(3600);
+    let mut estimator = SocEstimator::new(config);
+
+    let estimate = estimator
+        .update(&frame(0, &[("V", "13500"), ("I", "1000"), ("CS", "5")]))
+        .unwrap();
+    assert_eq!((estimate.soc, estimate.confidence, estimate.method), (1.0, Confidence::High, SocMethod::Float));
+
+    // 10 A discharge for an hour takes 10% of 100 Ah
+    let estimate = estimator
+        .update(&frame(3600, &[("V", "13100"), ("I", "-10000"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!(estimate.method, SocMethod::CoulombCounting);
+    assert_eq!(estimate.confidence, Confidence::Medium);
+    assert_eq!(round(estimate.soc), 0.9);
+    let previous = estimate.clone();
+
+    // frames without voltage keep estimate
+    assert_eq!(estimator.update(&frame(3601, &[("PPV", "0")])).unwrap(), &previous);
+}
+
+#[test]
+fn battery_monitor_rest_test() {
+    let mut estimator = SocEstimator::new(SocConfig::new(Chemistry::FloodedLeadAcid));
+
+    estimator.update(&frame(0, &[("V", "12200"), ("I", "0"), ("CE", "-20000")]));
+    let estimate = estimator
+        .update(&frame(1800, &[("V", "12200"), ("I", "0"), ("CE", "-20000")]))
+        .unwrap();
+    assert_eq!((estimate.method, estimate.confidence), (SocMethod::RestVoltage, Confidence::High));
+}
+
+#[test]
+fn load_output_test() {
+    let mut config = SocConfig::new(Chemistry::Agm);
+    config.capacity = Some(100.0);
+    config.max_gap = Duration::from_secs(3600);
+    let mut estimator = SocEstimator::new(config);
+
+    estimator.update(&frame(0, &[("V", "13500"), ("I", "0"), ("IL", "0"), ("CS", "5")]));
+    // at night load output drains the battery, battery current already includes it
+    let estimate = estimator
+        .update(&frame(1800, &[("V", "12500"), ("I", "-5000"), ("IL", "5000"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!(estimate.method, SocMethod::CoulombCounting);
+    assert_eq!(round(estimate.soc), 0.975);
+    let estimate = estimator
+        .update(&frame(3600, &[("V", "12500"), ("I", "-5000"), ("IL", "5000"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!(estimate.method, SocMethod::CoulombCounting);
+    assert_eq!(round(estimate.soc), 0.95);
+}
+
+#[test]
+fn counting_confidence_test() {
+    let mut config = SocConfig::new(Chemistry::Agm);
+    config.capacity = Some(100.0);
+    config.max_gap = Duration::from_secs(3600);
+    config.max_counting = Duration::from_secs(7200);
+    let mut estimator = SocEstimator::new(config);
+
+    estimator.update(&frame(0, &[("V", "13500"), ("I", "1000"), ("CS", "5")]));
+    for time in [3600, 7200] {
+        let estimate = estimator
+            .update(&frame(time, &[("V", "12500"), ("I", "-1000"), ("CS", "0")]))
+            .unwrap();
+        assert_eq!(estimate.confidence, Confidence::Medium);
+    }
+    let estimate = estimator
+        .update(&frame(10800, &[("V", "12500"), ("I", "-1000"), ("CS", "0")]))
+        .unwrap();
+    assert_eq!((estimate.method, estimate.confidence), (SocMethod::CoulombCounting, Confidence::Low));
+}
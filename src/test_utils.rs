@@ -0,0 +1,26 @@
+//! Helpers shared by unit tests of modules working on converted records
+
+use crate::converter::convert;
+use crate::converter::models::VEDirectData;
+use std::collections::HashMap;
+
+/// converts record with given raw fields
+pub fn data_from(fields: &[(&str, &str)]) -> VEDirectData {
+    let mut map = HashMap::<String, Vec<u8>>::new();
+    for (label, value) in fields {
+        map.insert(label.to_string(), value.as_bytes().to_vec());
+    }
+    convert(map).unwrap()
+}
+
+/// converts record with given raw fields received at unix timestamp
+pub fn frame(time: i64, fields: &[(&str, &str)]) -> VEDirectData {
+    let time = time.to_string();
+    let mut fields = fields.to_vec();
+    fields.push(("Time", &time));
+    data_from(&fields)
+}
+
+pub fn round(val: f64) -> f64 {
+    (val * 1000_f64).round() / 1000_f64
+}
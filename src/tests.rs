@@ -1,5 +1,6 @@
-use crate::converter::convert;
-use crate::converter::models::{Alarm, AlarmReason, ErrorCode, Load, Relay, StateOfOperation};
+use crate::converter::models::{Alarm, AlarmReason, ErrorCode, Load, Relay, StateOfOperation, TimeToGo};
+use crate::converter::v1::convert_v1;
+use crate::converter::{convert, revert};
 use crate::parser::Parser;
 
 #[test]
@@ -9,8 +10,9 @@ fn pipe_line1() {
     let mut parser = Parser::new();
     let parse = parser.parse_slice(data).expect("parsing failed");
     println!("{:?}", parse);
-    let conv = convert(parse).unwrap();
+    let conv = convert(parse.clone()).unwrap();
     println!("{:?}", conv);
+    assert_eq!(revert(&conv), parse);
     assert_eq!(conv.PID.unwrap(), "0xA053".to_string());
     assert_eq!(conv.FW.unwrap(), "159".to_string());
     assert_eq!(conv.SER.unwrap(), "HQ2132QY2KR".to_string());
@@ -39,14 +41,15 @@ fn pipe_line2() {
 
     let mut parser = Parser::new();
     let parse = parser.parse_slice(vec_data.as_slice()).expect("parsing failed");
-    let conv = convert(parse).unwrap();
+    let conv = convert(parse.clone()).unwrap();
+    assert_eq!(revert(&conv), parse);
     assert_eq!(conv.PID.unwrap(), "0x203".to_string());
     assert_eq!(conv.V.unwrap(), 26.201);
     assert_eq!(conv.I.unwrap(), 0.0);
     assert_eq!(conv.P.unwrap(), 0.0);
     assert_eq!(conv.CE.unwrap(), 0.0);
-    assert_eq!(conv.SOC.unwrap(), 1000.0);
-    assert_eq!(conv.TTG.unwrap(), -60.0);
+    assert_eq!(conv.SOC.unwrap(), 1.0);
+    assert_eq!(conv.TTG.unwrap(), TimeToGo::Infinite);
     assert_eq!(conv.Alarm.unwrap(), Alarm::Off);
     assert_eq!(conv.Relay.unwrap(), Relay::Off);
     assert_eq!(conv.AR.unwrap(), [AlarmReason::None]);
@@ -54,3 +57,19 @@ fn pipe_line2() {
     assert_eq!(conv.FW.unwrap(), "0307".to_string());
     assert_eq!(conv.Checksum.unwrap(), 0xd8);
 }
+
+#[test]
+fn pipe_line2_v1() {
+    let data = "\r\nPID\t0x203\r\nV\t26201\r\nI\t0\r\nP\t0\r\nCE\t0\r\nSOC\t1000\r\nTTG\t-1\r\nAlarm\tOFF\r\nRelay\tOFF\r\nAR\t0\r\nBMV\t700\r\nFW\t0307\r\nChecksum\t"
+        .as_bytes();
+    let mut vec_data = data.to_vec();
+    vec_data.push(0x11);
+
+    let mut parser = Parser::new();
+    let parse = parser.parse_slice(vec_data.as_slice()).expect("parsing failed");
+    let conv = convert_v1(parse).unwrap();
+    assert_eq!(conv.V.unwrap(), 26.201);
+    assert_eq!(conv.SOC.unwrap(), 1000.0);
+    assert_eq!(conv.TTG.unwrap(), -60.0);
+    assert_eq!(conv.FW.unwrap(), "0307".to_string());
+}
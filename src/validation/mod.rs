@@ -0,0 +1,169 @@
+//! # Example
+//! ```
+//! let validator = Validator::default().family_range(ProductFamily::SolarCharger, Labels::VPV, Range::new(0.0, 150.0));
+//! // converted variable from converter output
+//! for issue in validator.sanitize(&mut converted) {
+//!     println!("dropped {} = {}", issue.label, issue.value);
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::models::{Labels, VEDirectData};
+use crate::device::detect_family;
+use crate::product::models::ProductFamily;
+use crate::product::product_info;
+use std::collections::HashMap;
+
+/// ranges applying to every device
+pub fn default_ranges() -> HashMap<Labels, Range> {
+    HashMap::from([
+        (Labels::V, Range::new(0.0, 70.0)),
+        (Labels::VS, Range::new(0.0, 70.0)),
+        (Labels::V2, Range::new(0.0, 70.0)),
+        (Labels::V3, Range::new(0.0, 70.0)),
+        (Labels::VM, Range::new(0.0, 70.0)),
+        (Labels::DM, Range::new(-1.0, 1.0)),
+        (Labels::VPV, Range::new(0.0, 500.0)),
+        (Labels::PPV, Range::new(0.0, 15000.0)),
+        (Labels::I, Range::new(-2000.0, 2000.0)),
+        (Labels::I2, Range::new(0.0, 100.0)),
+        (Labels::I3, Range::new(0.0, 100.0)),
+        (Labels::IL, Range::new(0.0, 100.0)),
+        (Labels::T, Range::new(-40.0, 100.0)),
+        (Labels::P, Range::new(-100000.0, 100000.0)),
+        (Labels::SOC, Range::new(0.0, 1.0)),
+        (Labels::TTG, Range::new(0.0, f64::INFINITY)),
+        (Labels::H7, Range::new(0.0, 70.0)),
+        (Labels::H8, Range::new(0.0, 70.0)),
+        (Labels::H15, Range::new(0.0, 70.0)),
+        (Labels::H16, Range::new(0.0, 70.0)),
+        (Labels::H19, Range::new(0.0, f64::MAX)),
+        (Labels::H20, Range::new(0.0, 200000.0)),
+        (Labels::H21, Range::new(0.0, 15000.0)),
+        (Labels::H22, Range::new(0.0, 200000.0)),
+        (Labels::H23, Range::new(0.0, 15000.0)),
+        (Labels::HSDS, Range::new(0.0, 364.0)),
+        (Labels::AC_OUT_V, Range::new(0.0, 300.0)),
+        (Labels::AC_OUT_I, Range::new(0.0, 100.0)),
+        (Labels::AC_OUT_S, Range::new(0.0, 15000.0)),
+        (Labels::DC_IN_V, Range::new(0.0, 70.0)),
+        (Labels::DC_IN_I, Range::new(0.0, 200.0)),
+        (Labels::DC_IN_P, Range::new(0.0, 10000.0)),
+    ])
+}
+
+/// Checks numeric fields against plausible ranges
+#[derive(PartialEq, Debug, Clone)]
+pub struct Validator {
+    ranges: HashMap<Labels, Range>,
+    family_ranges: HashMap<ProductFamily, HashMap<Labels, Range>>,
+    use_ratings: bool,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self {
+            ranges: default_ranges(),
+            family_ranges: HashMap::new(),
+            use_ratings: true,
+        }
+    }
+}
+
+impl Validator {
+    /// validator without any ranges
+    pub fn new() -> Self {
+        Self {
+            ranges: HashMap::new(),
+            family_ranges: HashMap::new(),
+            use_ratings: false,
+        }
+    }
+
+    pub fn range(mut self, label: Labels, range: Range) -> Self {
+        self.ranges.insert(label, range);
+        self
+    }
+
+    /// range applying to devices of family, takes precedence over ranges from ratings and general ones
+    pub fn family_range(mut self, family: ProductFamily, label: Labels, range: Range) -> Self {
+        self.family_ranges.entry(family).or_default().insert(label, range);
+        self
+    }
+
+    /// narrows ranges using nominal ratings of product known from PID
+    pub fn use_ratings(mut self, use_ratings: bool) -> Self {
+        self.use_ratings = use_ratings;
+        self
+    }
+
+    /// ranges applying to record, resolved from its PID or observed labels
+    pub fn ranges_for(&self, data: &VEDirectData) -> HashMap<Labels, Range> {
+        let mut ranges = self.ranges.clone();
+
+        let product = data.PID.as_deref().and_then(product_info);
+        if let (true, Some(product)) = (self.use_ratings, product) {
+            let ratings = &product.ratings;
+            let mut narrow = |label: Labels, max: Option<f64>, symmetric: bool| {
+                if let Some(max) = max {
+                    let max = max * RATING_MARGIN;
+                    let min = if symmetric { -max } else { 0_f64 };
+                    ranges.insert(label, Range::new(min, max));
+                }
+            };
+            // dc-dc converters report output as V and input as DC_IN_V
+            let (battery_voltage, input_voltage) = match product.family {
+                ProductFamily::DcDcConverter => (ratings.output_voltage, ratings.battery_voltage),
+                _ => (ratings.battery_voltage, None),
+            };
+            narrow(Labels::V, battery_voltage, false);
+            narrow(Labels::DC_IN_V, input_voltage, false);
+            narrow(Labels::VPV, ratings.pv_voltage, false);
+            narrow(Labels::I, ratings.current, true);
+            narrow(Labels::AC_OUT_V, ratings.ac_voltage, false);
+            narrow(Labels::AC_OUT_S, ratings.power, false);
+        }
+
+        let family = product.map(|product| product.family).or_else(|| detect_family(data));
+        if let Some(family_ranges) = family.and_then(|family| self.family_ranges.get(&family)) {
+            ranges.extend(family_ranges.iter().map(|(label, range)| (*label, *range)));
+        }
+        ranges
+    }
+
+    /// reports values outside of plausible ranges
+    pub fn validate(&self, data: &VEDirectData) -> Vec<ValidationIssue> {
+        let ranges = self.ranges_for(data);
+        let mut issues: Vec<ValidationIssue> = ranges
+            .iter()
+            .filter_map(|(label, range)| {
+                let value = data.numeric(*label)?;
+                if range.contains(value) {
+                    None
+                } else {
+                    Some(ValidationIssue {
+                        label: *label,
+                        value,
+                        range: *range,
+                    })
+                }
+            })
+            .collect();
+        issues.sort_by_key(|issue| issue.label as usize);
+        issues
+    }
+
+    /// drops implausible values from record and reports them
+    pub fn sanitize(&self, data: &mut VEDirectData) -> Vec<ValidationIssue> {
+        let issues = self.validate(data);
+        for issue in issues.iter() {
+            data.clear(issue.label);
+        }
+        issues
+    }
+}
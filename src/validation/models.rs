@@ -0,0 +1,31 @@
+use crate::converter::models::Labels;
+use serde::{Deserialize, Serialize};
+
+/// Plausible range of value in normalised unit, bounds inclusive
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Range {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Range {
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// NaN is never plausible
+    pub fn contains(&self, val: f64) -> bool {
+        self.min <= val && val <= self.max
+    }
+}
+
+/// Failed plausibility check
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub label: Labels,
+    pub value: f64,
+    pub range: Range,
+}
+
+/// Nominal ratings of product known from PID are multiplied by this margin to get plausible maximum
+pub const RATING_MARGIN: f64 = 1.5;
@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_utils::data_from;
+
+#[test]
+fn validate_test() {
+    let data = data_from(&[
+        ("V", "12540"),
+        ("VPV", "6000000"),
+        ("SOC", "1200"),
+        ("TTG", "-1"),
+        ("T", "25"),
+    ]);
+    let issues = Validator::default().validate(&data);
+
+    assert_eq!(issues.iter().map(|issue| issue.label).collect::<Vec<_>>(), vec![Labels::VPV, Labels::SOC]);
+    assert_eq!(issues[0].value, 6000.0);
+    assert_eq!(issues[1].range, Range::new(0.0, 1.0));
+    assert_eq!(serde_json::to_value(&issues[0]).unwrap()["label"], "VPV");
+
+    assert!(Validator::new().validate(&data).is_empty());
+}
+
+#[test]
+fn ratings_test() {
+    // SmartSolar MPPT 75/15
+    let data = data_from(&[("PID", "0xA053"), ("V", "12540"), ("VPV", "120000"), ("I", "20000")]);
+
+    let issues = Validator::default().validate(&data);
+    assert_eq!(issues.iter().map(|issue| issue.label).collect::<Vec<_>>(), vec![Labels::VPV]);
+    assert_eq!(issues[0].range, Range::new(0.0, 112.5));
+
+    assert!(Validator::default().use_ratings(false).validate(&data).is_empty());
+}
+
+#[test]
+fn family_range_test() {
+    let validator = Validator::default().family_range(ProductFamily::SolarCharger, Labels::I, Range::new(-1.0, 10.0));
+
+    // family detected from observed labels without PID
+    let mppt = data_from(&[("VPV", "18000"), ("PPV", "100"), ("I", "20000")]);
+    assert_eq!(validator.validate(&mppt)[0].label, Labels::I);
+
+    let bmv = data_from(&[("SOC", "500"), ("I", "20000")]);
+    assert!(validator.validate(&bmv).is_empty());
+}
+
+#[test]
+fn sanitize_test() {
+    let mut data = data_from(&[("V", "12540"), ("T", "250")]);
+
+    let issues = Validator::default().sanitize(&mut data);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(data.T, None);
+    assert_eq!(data.V.unwrap(), 12.54);
+}
@@ -0,0 +1,155 @@
+//! # Example
+//! ```
+//! let mut watchdog = Watchdog::new(Duration::from_secs(10));
+//! loop {
+//!     // read with timeout so watchdog is checked even when nothing arrives
+//!     if let Ok(r) = port.read(&mut buf).await {
+//!         for event in watchdog.feed("/dev/serial0", &buf[..r]) {
+//!             println!("{:?}", event);
+//!         }
+//!     }
+//!     for event in watchdog.check() {
+//!         println!("{:?}", event);
+//!     }
+//! }
+//! ```
+
+pub mod models;
+
+#[cfg(test)]
+mod tests;
+
+use self::models::*;
+use crate::converter::convert;
+use crate::parser::Parser;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::time::Duration;
+
+struct WatchedDevice {
+    parser: Parser,
+    watched_since: i64,
+    last_valid: Option<i64>,
+    online: bool,
+    offline_reported: bool,
+    latest: Option<CachedState>,
+}
+
+/// Parses data of devices and tracks time since their last valid frame,
+/// frame is valid when it converts and its calculated checksum is 0
+pub struct Watchdog {
+    timeout: Duration,
+    devices: HashMap<String, WatchedDevice>,
+}
+
+impl Watchdog {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            devices: HashMap::new(),
+        }
+    }
+
+    /// starts watching device before any data arrives, so it is reported offline when it never sends a frame
+    pub fn watch(&mut self, device: &str) {
+        self.watch_at(device, Utc::now().timestamp())
+    }
+
+    pub fn watch_at(&mut self, device: &str, now: i64) {
+        self.devices.entry(device.to_string()).or_insert_with(|| WatchedDevice {
+            parser: Parser::new(),
+            watched_since: now,
+            last_valid: None,
+            online: false,
+            offline_reported: false,
+            latest: None,
+        });
+    }
+
+    /// parses data read from device, returns DeviceOnline when first valid frame arrives after being offline
+    pub fn feed(&mut self, device: &str, data: &[u8]) -> Vec<WatchdogEvent> {
+        self.feed_at(device, data, Utc::now().timestamp())
+    }
+
+    pub fn feed_at(&mut self, device: &str, data: &[u8], now: i64) -> Vec<WatchdogEvent> {
+        self.watch_at(device, now);
+        let mut events = Vec::new();
+        let watched = match self.devices.get_mut(device) {
+            Some(watched) => watched,
+            None => return events,
+        };
+
+        let mut chunk = data;
+        // one chunk may hold more than one frame, parser keeps the rest buffered
+        while let Ok(parse) = watched.parser.parse_slice(chunk) {
+            chunk = &[];
+            let data = match convert(parse) {
+                Ok(data) if data.Calc_sum == Some(0) => data,
+                _ => continue,
+            };
+            watched.last_valid = Some(now);
+            watched.latest = Some(CachedState {
+                data,
+                received: now,
+                stale: false,
+            });
+            watched.offline_reported = false;
+            if !watched.online {
+                watched.online = true;
+                events.push(WatchdogEvent::DeviceOnline {
+                    device: device.to_string(),
+                    time: now,
+                });
+            }
+        }
+        events
+    }
+
+    /// reports devices without valid frame within timeout and marks their cached state stale
+    pub fn check(&mut self) -> Vec<WatchdogEvent> {
+        self.check_at(Utc::now().timestamp())
+    }
+
+    pub fn check_at(&mut self, now: i64) -> Vec<WatchdogEvent> {
+        let timeout = self.timeout.as_secs() as i64;
+        let mut devices: Vec<_> = self.devices.iter_mut().collect();
+        devices.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut events = Vec::new();
+        for (device, watched) in devices {
+            let since = watched.last_valid.unwrap_or(watched.watched_since);
+            if now - since < timeout {
+                continue;
+            }
+            if let Some(latest) = watched.latest.as_mut() {
+                latest.stale = true;
+            }
+            // device that never sent valid frame is reported once as well
+            if !watched.offline_reported {
+                watched.online = false;
+                watched.offline_reported = true;
+                events.push(WatchdogEvent::DeviceOffline {
+                    device: device.clone(),
+                    time: now,
+                    last_seen: watched.last_valid,
+                });
+            }
+        }
+        events
+    }
+
+    /// latest valid frame of device
+    pub fn latest(&self, device: &str) -> Option<&CachedState> {
+        self.devices.get(device)?.latest.as_ref()
+    }
+
+    /// time since last valid frame of device
+    pub fn since_last_frame(&self, device: &str, now: i64) -> Option<Duration> {
+        let last = self.devices.get(device)?.last_valid?;
+        Some(Duration::from_secs((now - last).max(0) as u64))
+    }
+
+    pub fn is_online(&self, device: &str) -> bool {
+        self.devices.get(device).map(|watched| watched.online).unwrap_or(false)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::converter::models::VEDirectData;
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+pub enum WatchdogEvent {
+    DeviceOnline {
+        device: String,
+        time: i64, // [s] unix timestamp of first valid frame
+    },
+    DeviceOffline {
+        device: String,
+        time: i64,              // [s] unix timestamp timeout was detected at
+        last_seen: Option<i64>, // [s] unix timestamp of last valid frame, None when device never sent one
+    },
+}
+
+/// Latest valid frame of device
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct CachedState {
+    pub data: VEDirectData,
+    pub received: i64, // [s] unix timestamp
+    pub stale: bool,   // no valid frame arrived within timeout since this one
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+fn frame(v: &str) -> Vec<u8> {
+    let mut data = format!("\r\nPID\t0xA053\r\nV\t{}\r\nChecksum\t", v).into_bytes();
+    let sum = data.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+    data.push(0u8.wrapping_sub(sum));
+    data
+}
+
+#[test]
+fn online_offline_test() {
+    let mut watchdog = Watchdog::new(Duration::from_secs(10));
+    watchdog.watch_at("mppt", 0);
+    assert!(!watchdog.is_online("mppt"));
+    assert_eq!(watchdog.check_at(5), []);
+
+    let events = watchdog.feed_at("mppt", &frame("12540"), 6);
+    assert_eq!(
+        events,
+        [WatchdogEvent::DeviceOnline {
+            device: "mppt".to_string(),
+            time: 6
+        }]
+    );
+    assert!(watchdog.is_online("mppt"));
+    assert_eq!(watchdog.feed_at("mppt", &frame("12550"), 7), []);
+    assert_eq!(watchdog.since_last_frame("mppt", 12).unwrap(), Duration::from_secs(5));
+
+    // cable dropped, parser waits for data forever
+    assert_eq!(watchdog.check_at(16), []);
+    let events = watchdog.check_at(17);
+    assert_eq!(
+        events,
+        [WatchdogEvent::DeviceOffline {
+            device: "mppt".to_string(),
+            time: 17,
+            last_seen: Some(7)
+        }]
+    );
+    assert_eq!(watchdog.check_at(30), []);
+    let latest = watchdog.latest("mppt").unwrap();
+    assert!(latest.stale);
+    assert_eq!(latest.received, 7);
+    assert_eq!(latest.data.V.unwrap(), 12.55);
+
+    let events = watchdog.feed_at("mppt", &frame("12560"), 31);
+    assert!(matches!(events[..], [WatchdogEvent::DeviceOnline { time: 31, .. }]));
+    assert!(!watchdog.latest("mppt").unwrap().stale);
+}
+
+#[test]
+fn invalid_frames_test() {
+    let mut watchdog = Watchdog::new(Duration::from_secs(10));
+    let mut data = frame("12540");
+    *data.last_mut().unwrap() ^= 0xff;
+    assert_eq!(watchdog.feed_at("bmv", &data, 0), []);
+    assert_eq!(watchdog.feed_at("bmv", b"\r\nV\t125", 5), []);
+    assert!(watchdog.latest("bmv").is_none());
+
+    let events = watchdog.check_at(10);
+    assert_eq!(
+        events,
+        [WatchdogEvent::DeviceOffline {
+            device: "bmv".to_string(),
+            time: 10,
+            last_seen: None
+        }]
+    );
+    assert_eq!(watchdog.check_at(20), []);
+}
+
+#[test]
+fn multiple_frames_test() {
+    let mut watchdog = Watchdog::new(Duration::from_secs(10));
+    let mut data = frame("12540");
+    data.extend(frame("12600"));
+    let events = watchdog.feed_at("mppt", &data[..data.len() - 3], 0);
+    assert_eq!(events.len(), 1);
+    assert_eq!(watchdog.latest("mppt").unwrap().data.V.unwrap(), 12.54);
+    watchdog.feed_at("mppt", &data[data.len() - 3..], 1);
+    assert_eq!(watchdog.latest("mppt").unwrap().data.V.unwrap(), 12.6);
+    assert_eq!(watchdog.since_last_frame("other", 1), None);
+}